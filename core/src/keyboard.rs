@@ -2,7 +2,9 @@
 mod event;
 mod key_code;
 mod modifiers_state;
+mod shortcut;
 
 pub use event::Event;
 pub use key_code::KeyCode;
 pub use modifiers_state::ModifiersState;
+pub use shortcut::{ParseShortcutError, Shortcut};
@@ -6,7 +6,8 @@ use super::{KeyCode, ModifiersState};
 /// additional events, feel free to [open an issue] and share your use case!_
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard key was pressed.
     KeyPressed {
@@ -31,4 +32,12 @@ pub enum Event {
 
     /// The keyboard modifiers have changed.
     ModifiersChanged(ModifiersState),
+
+    /// An input method is composing some text that is not committed yet.
+    ///
+    /// An empty string means the composition was cleared.
+    ImePreedit(String),
+
+    /// An input method committed the text it was composing.
+    ImeCommit(String),
 }
@@ -1,5 +1,6 @@
 /// The current state of the keyboard modifiers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModifiersState {
     /// Whether a shift key is pressed
     pub shift: bool,
@@ -15,6 +16,28 @@ pub struct ModifiersState {
 }
 
 impl ModifiersState {
+    /// The command modifier of the current platform.
+    ///
+    /// It is the logo key on macOS and the control key elsewhere.
+    pub const COMMAND: ModifiersState = ModifiersState {
+        shift: false,
+        control: !cfg!(target_os = "macos"),
+        alt: false,
+        logo: cfg!(target_os = "macos"),
+    };
+
+    /// Returns true if the command modifier of the current platform is
+    /// pressed.
+    ///
+    /// It is the logo key on macOS and the control key elsewhere.
+    pub fn is_command_pressed(&self) -> bool {
+        if cfg!(target_os = "macos") {
+            self.logo
+        } else {
+            self.control
+        }
+    }
+
     /// Returns true if the current [`ModifiersState`] has at least the same
     /// modifiers enabled as the given value, and false otherwise.
     ///
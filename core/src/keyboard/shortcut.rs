@@ -0,0 +1,431 @@
+use super::{KeyCode, ModifiersState};
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A combination of keyboard modifiers and a key that triggers an action.
+///
+/// A [`Shortcut`] can be parsed from a string, where the modifiers and the
+/// key are separated by `+`:
+///
+/// ```
+/// # use iced_core::keyboard::{KeyCode, ModifiersState, Shortcut};
+/// #
+/// let save: Shortcut = "Ctrl+Shift+S".parse().unwrap();
+///
+/// assert_eq!(save.key_code, KeyCode::S);
+/// assert!(save.modifiers.control && save.modifiers.shift);
+///
+/// // `Cmd` is the command modifier of the platform: the logo key on macOS
+/// // and the control key elsewhere.
+/// let copy: Shortcut = "Cmd+C".parse().unwrap();
+///
+/// assert_eq!(copy.modifiers, ModifiersState::COMMAND);
+/// ```
+///
+/// Modifiers and keys are case-insensitive. The supported modifiers are
+/// `Ctrl`, `Shift`, `Alt`, `Logo` and `Cmd`, with some aliases like
+/// `Control`, `Option` or `Super`.
+///
+/// Symbols can be written as they are or by name, like `Ctrl+-` or
+/// `Ctrl+Minus`. `Plus` and `+` refer to the `+` key, so `Ctrl++` is a valid
+/// shortcut. It is triggered by the `+` key of the numeric keypad and by the
+/// `=` key of the main keyboard, with or without `Shift`, since most layouts
+/// need it to type a `+`.
+///
+/// [`Shortcut`]: struct.Shortcut.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The modifiers that must be pressed, and no others
+    pub modifiers: ModifiersState,
+
+    /// The key that triggers the [`Shortcut`]
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub key_code: KeyCode,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`] with the given modifiers and key.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub const fn new(modifiers: ModifiersState, key_code: KeyCode) -> Self {
+        Shortcut {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns true if the [`Shortcut`] is triggered by pressing the given
+    /// key with exactly the given modifiers.
+    ///
+    /// A `+` key also matches the `=` key, with or without `Shift`.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn matches(
+        &self,
+        key_code: KeyCode,
+        modifiers: ModifiersState,
+    ) -> bool {
+        if self.key_code == KeyCode::Add && key_code == KeyCode::Equals {
+            let unshifted = ModifiersState {
+                shift: false,
+                ..modifiers
+            };
+
+            return !self.modifiers.shift && self.modifiers == unshifted;
+        }
+
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ParseShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        // The key itself may be a `+`, like in `Ctrl++`
+        let (modifiers, key) = if s == "+" {
+            ("", s)
+        } else if let Some(modifiers) = s.strip_suffix("++") {
+            (modifiers, "+")
+        } else {
+            match s.rfind('+') {
+                Some(i) => (&s[..i], &s[i + 1..]),
+                None => ("", s),
+            }
+        };
+
+        let key = key.trim();
+
+        if key.is_empty() {
+            return Err(ParseShortcutError::MissingKey);
+        }
+
+        let key_code = KEYS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, key_code)| *key_code)
+            .ok_or_else(|| ParseShortcutError::UnknownKey(key.to_owned()))?;
+
+        let mut modifiers_state = ModifiersState::default();
+
+        for modifier in modifiers.split('+').map(str::trim) {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers_state.control = true,
+                "shift" => modifiers_state.shift = true,
+                "alt" | "option" => modifiers_state.alt = true,
+                "logo" | "super" | "meta" | "win" => {
+                    modifiers_state.logo = true
+                }
+                "cmd" | "command" | "cmdorctrl" => {
+                    modifiers_state.control |= ModifiersState::COMMAND.control;
+                    modifiers_state.logo |= ModifiersState::COMMAND.logo;
+                }
+                "" if modifiers.is_empty() => {}
+                _ => {
+                    return Err(ParseShortcutError::UnknownModifier(
+                        modifier.to_owned(),
+                    ))
+                }
+            }
+        }
+
+        Ok(Shortcut::new(modifiers_state, key_code))
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.control {
+            write!(f, "Ctrl+")?;
+        }
+
+        if self.modifiers.alt {
+            write!(f, "Alt+")?;
+        }
+
+        if self.modifiers.shift {
+            write!(f, "Shift+")?;
+        }
+
+        if self.modifiers.logo {
+            if cfg!(target_os = "macos") {
+                write!(f, "Cmd+")?;
+            } else {
+                write!(f, "Logo+")?;
+            }
+        }
+
+        match KEYS.iter().find(|(_, key_code)| *key_code == self.key_code) {
+            Some((name, _)) => write!(f, "{}", name),
+            None => write!(f, "{:?}", self.key_code),
+        }
+    }
+}
+
+/// An error produced when parsing a [`Shortcut`].
+///
+/// [`Shortcut`]: struct.Shortcut.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseShortcutError {
+    /// The shortcut has no key.
+    MissingKey,
+
+    /// The key of the shortcut is not supported.
+    UnknownKey(String),
+
+    /// A modifier of the shortcut is not supported.
+    UnknownModifier(String),
+}
+
+impl fmt::Display for ParseShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseShortcutError::MissingKey => {
+                write!(f, "the shortcut has no key")
+            }
+            ParseShortcutError::UnknownKey(key) => {
+                write!(f, "unknown key: {}", key)
+            }
+            ParseShortcutError::UnknownModifier(modifier) => {
+                write!(f, "unknown modifier: {}", modifier)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseShortcutError {}
+
+/// The names of the keys that can be used in a [`Shortcut`].
+///
+/// The first name of every key is used to display it.
+///
+/// [`Shortcut`]: struct.Shortcut.html
+const KEYS: &[(&str, KeyCode)] = &[
+    ("A", KeyCode::A),
+    ("B", KeyCode::B),
+    ("C", KeyCode::C),
+    ("D", KeyCode::D),
+    ("E", KeyCode::E),
+    ("F", KeyCode::F),
+    ("G", KeyCode::G),
+    ("H", KeyCode::H),
+    ("I", KeyCode::I),
+    ("J", KeyCode::J),
+    ("K", KeyCode::K),
+    ("L", KeyCode::L),
+    ("M", KeyCode::M),
+    ("N", KeyCode::N),
+    ("O", KeyCode::O),
+    ("P", KeyCode::P),
+    ("Q", KeyCode::Q),
+    ("R", KeyCode::R),
+    ("S", KeyCode::S),
+    ("T", KeyCode::T),
+    ("U", KeyCode::U),
+    ("V", KeyCode::V),
+    ("W", KeyCode::W),
+    ("X", KeyCode::X),
+    ("Y", KeyCode::Y),
+    ("Z", KeyCode::Z),
+    ("0", KeyCode::Key0),
+    ("1", KeyCode::Key1),
+    ("2", KeyCode::Key2),
+    ("3", KeyCode::Key3),
+    ("4", KeyCode::Key4),
+    ("5", KeyCode::Key5),
+    ("6", KeyCode::Key6),
+    ("7", KeyCode::Key7),
+    ("8", KeyCode::Key8),
+    ("9", KeyCode::Key9),
+    ("F1", KeyCode::F1),
+    ("F2", KeyCode::F2),
+    ("F3", KeyCode::F3),
+    ("F4", KeyCode::F4),
+    ("F5", KeyCode::F5),
+    ("F6", KeyCode::F6),
+    ("F7", KeyCode::F7),
+    ("F8", KeyCode::F8),
+    ("F9", KeyCode::F9),
+    ("F10", KeyCode::F10),
+    ("F11", KeyCode::F11),
+    ("F12", KeyCode::F12),
+    ("F13", KeyCode::F13),
+    ("F14", KeyCode::F14),
+    ("F15", KeyCode::F15),
+    ("F16", KeyCode::F16),
+    ("F17", KeyCode::F17),
+    ("F18", KeyCode::F18),
+    ("F19", KeyCode::F19),
+    ("F20", KeyCode::F20),
+    ("F21", KeyCode::F21),
+    ("F22", KeyCode::F22),
+    ("F23", KeyCode::F23),
+    ("F24", KeyCode::F24),
+    ("Escape", KeyCode::Escape),
+    ("Esc", KeyCode::Escape),
+    ("Enter", KeyCode::Enter),
+    ("Return", KeyCode::Enter),
+    ("Space", KeyCode::Space),
+    ("Tab", KeyCode::Tab),
+    ("Backspace", KeyCode::Backspace),
+    ("Delete", KeyCode::Delete),
+    ("Del", KeyCode::Delete),
+    ("Insert", KeyCode::Insert),
+    ("Ins", KeyCode::Insert),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PgUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("PgDn", KeyCode::PageDown),
+    ("Up", KeyCode::Up),
+    ("Down", KeyCode::Down),
+    ("Left", KeyCode::Left),
+    ("Right", KeyCode::Right),
+    ("Plus", KeyCode::Add),
+    ("+", KeyCode::Add),
+    ("Minus", KeyCode::Minus),
+    ("-", KeyCode::Minus),
+    ("Equals", KeyCode::Equals),
+    ("=", KeyCode::Equals),
+    ("Comma", KeyCode::Comma),
+    (",", KeyCode::Comma),
+    ("Period", KeyCode::Period),
+    (".", KeyCode::Period),
+    ("Slash", KeyCode::Slash),
+    ("/", KeyCode::Slash),
+    ("Backslash", KeyCode::Backslash),
+    ("\\", KeyCode::Backslash),
+    ("Semicolon", KeyCode::Semicolon),
+    (";", KeyCode::Semicolon),
+    ("Apostrophe", KeyCode::Apostrophe),
+    ("'", KeyCode::Apostrophe),
+    ("Grave", KeyCode::Grave),
+    ("`", KeyCode::Grave),
+    ("LBracket", KeyCode::LBracket),
+    ("[", KeyCode::LBracket),
+    ("RBracket", KeyCode::RBracket),
+    ("]", KeyCode::RBracket),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(shortcut: &str) -> Result<Shortcut, ParseShortcutError> {
+        shortcut.parse()
+    }
+
+    fn modifiers(control: bool, shift: bool, alt: bool) -> ModifiersState {
+        ModifiersState {
+            control,
+            shift,
+            alt,
+            logo: false,
+        }
+    }
+
+    #[test]
+    fn parsing_is_case_insensitive() {
+        let expected = Shortcut::new(modifiers(true, true, false), KeyCode::S);
+
+        assert_eq!(parse("Ctrl+Shift+S"), Ok(expected));
+        assert_eq!(parse("ctrl+shift+s"), Ok(expected));
+        assert_eq!(parse(" CTRL + Shift + s "), Ok(expected));
+    }
+
+    #[test]
+    fn modifiers_and_keys_have_aliases() {
+        assert_eq!(parse("Control+Option+Esc"), parse("Ctrl+Alt+Escape"));
+        assert_eq!(parse("Super+Return"), parse("Logo+Enter"));
+        assert_eq!(parse("Ctrl+-"), parse("Ctrl+Minus"));
+        assert_eq!(parse("Ctrl+,"), parse("Ctrl+Comma"));
+    }
+
+    #[test]
+    fn plus_can_be_the_key() {
+        let expected =
+            Shortcut::new(modifiers(true, false, false), KeyCode::Add);
+
+        assert_eq!(parse("Ctrl++"), Ok(expected));
+        assert_eq!(parse("Ctrl+Plus"), Ok(expected));
+        assert_eq!(
+            parse("+"),
+            Ok(Shortcut::new(ModifiersState::default(), KeyCode::Add))
+        );
+    }
+
+    #[test]
+    fn plus_matches_the_keypad_and_the_equals_key() {
+        let zoom_in = parse("Ctrl++").unwrap();
+        let control = modifiers(true, false, false);
+        let control_shift = modifiers(true, true, false);
+
+        assert!(zoom_in.matches(KeyCode::Add, control));
+        assert!(zoom_in.matches(KeyCode::Equals, control));
+        assert!(zoom_in.matches(KeyCode::Equals, control_shift));
+        assert!(!zoom_in.matches(KeyCode::Add, control_shift));
+        assert!(!zoom_in.matches(KeyCode::Equals, ModifiersState::default()));
+
+        let reset = parse("Ctrl+=").unwrap();
+
+        assert!(reset.matches(KeyCode::Equals, control));
+        assert!(!reset.matches(KeyCode::Equals, control_shift));
+        assert!(!reset.matches(KeyCode::Add, control));
+    }
+
+    #[test]
+    fn cmd_is_the_command_modifier_of_the_platform() {
+        let shortcut = parse("Cmd+C").unwrap();
+
+        assert_eq!(shortcut.modifiers, ModifiersState::COMMAND);
+        assert_eq!(parse("Command+C"), Ok(shortcut));
+        assert_eq!(parse("CmdOrCtrl+C"), Ok(shortcut));
+
+        if cfg!(target_os = "macos") {
+            assert_eq!(parse("Logo+C"), Ok(shortcut));
+        } else {
+            assert_eq!(parse("Ctrl+C"), Ok(shortcut));
+        }
+    }
+
+    #[test]
+    fn invalid_shortcuts_are_rejected() {
+        assert_eq!(parse(""), Err(ParseShortcutError::MissingKey));
+        assert_eq!(parse("Ctrl+"), Err(ParseShortcutError::MissingKey));
+        assert_eq!(
+            parse("Ctrl+Foo"),
+            Err(ParseShortcutError::UnknownKey(String::from("Foo")))
+        );
+        assert_eq!(
+            parse("Hyper+S"),
+            Err(ParseShortcutError::UnknownModifier(String::from("Hyper")))
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for (_, key_code) in KEYS {
+            let shortcut =
+                Shortcut::new(modifiers(true, true, true), *key_code);
+
+            assert_eq!(parse(&shortcut.to_string()), Ok(shortcut));
+        }
+
+        let shortcut = parse("Cmd+Shift+Minus").unwrap();
+
+        assert_eq!(parse(&shortcut.to_string()), Ok(shortcut));
+    }
+
+    #[test]
+    fn keys_are_displayed_by_name() {
+        assert_eq!(parse("Ctrl+-").unwrap().to_string(), "Ctrl+Minus");
+        assert_eq!(parse("Alt+,").unwrap().to_string(), "Alt+Comma");
+        assert_eq!(parse("Ctrl++").unwrap().to_string(), "Ctrl+Plus");
+        assert_eq!(parse("esc").unwrap().to_string(), "Escape");
+    }
+}
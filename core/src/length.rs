@@ -18,6 +18,16 @@ pub enum Length {
 
     /// Fill a fixed amount of space
     Units(u16),
+
+    /// Fill a percentage of the space available in the parent.
+    ///
+    /// `Length::Percent(30)` takes 30% of the available space. It behaves
+    /// like `Length::Shrink` when the available space is infinite.
+    ///
+    /// To bound the resulting size, wrap the element in a `Container` that
+    /// uses the percentage along with its `min_width` and `max_width`, or
+    /// `min_height` and `max_height`.
+    Percent(u16),
 }
 
 impl Length {
@@ -34,6 +44,7 @@ impl Length {
             Length::FillPortion(factor) => *factor,
             Length::Shrink => 0,
             Length::Units(_) => 0,
+            Length::Percent(_) => 0,
         }
     }
 }
@@ -13,15 +13,19 @@
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
-#![forbid(rust_2018_idioms)]
+// The `serde` derives allow lints that cannot be allowed once forbidden
+#![cfg_attr(not(feature = "serde"), forbid(rust_2018_idioms))]
+#![cfg_attr(feature = "serde", deny(rust_2018_idioms))]
 pub mod keyboard;
 pub mod mouse;
+pub mod touch;
 
 mod align;
 mod background;
 mod color;
 mod font;
 mod length;
+mod padding;
 mod point;
 mod rectangle;
 mod size;
@@ -32,6 +36,7 @@ pub use background::Background;
 pub use color::Color;
 pub use font::Font;
 pub use length::Length;
+pub use padding::Padding;
 pub use point::Point;
 pub use rectangle::Rectangle;
 pub use size::Size;
@@ -1,3 +1,4 @@
+use crate::Padding;
 use std::f32;
 
 /// An amount of space in 2 dimensions.
@@ -37,10 +38,10 @@ impl Size {
     /// Increments the [`Size`] to account for the given padding.
     ///
     /// [`Size`]: struct.Size.html
-    pub fn pad(&self, padding: f32) -> Self {
+    pub fn pad(&self, padding: Padding) -> Self {
         Size {
-            width: self.width + padding * 2.0,
-            height: self.height + padding * 2.0,
+            width: self.width + f32::from(padding.horizontal()),
+            height: self.height + f32::from(padding.vertical()),
         }
     }
 }
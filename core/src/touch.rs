@@ -0,0 +1,4 @@
+//! Reuse basic touch types.
+mod event;
+
+pub use event::{Event, Finger};
@@ -0,0 +1,48 @@
+use crate::Point;
+
+/// A unique identifier representing a finger on a touch interaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Finger(pub u64);
+
+/// A touch interaction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// A touch interaction was started.
+    FingerPressed {
+        /// The finger of the interaction
+        id: Finger,
+
+        /// The position of the finger
+        position: Point,
+    },
+
+    /// An on-going touch interaction was moved.
+    FingerMoved {
+        /// The finger of the interaction
+        id: Finger,
+
+        /// The new position of the finger
+        position: Point,
+    },
+
+    /// A touch interaction was ended.
+    FingerLifted {
+        /// The finger of the interaction
+        id: Finger,
+
+        /// The last position of the finger
+        position: Point,
+    },
+
+    /// A touch interaction was canceled, usually because the system took
+    /// over it.
+    FingerLost {
+        /// The finger of the interaction
+        id: Finger,
+
+        /// The last position of the finger
+        position: Point,
+    },
+}
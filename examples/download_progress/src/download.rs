@@ -38,18 +38,16 @@ where
 
                         match response {
                             Ok(response) => {
-                                if let Some(total) = response.content_length() {
-                                    Some((
-                                        Progress::Started,
-                                        State::Downloading {
-                                            response,
-                                            total,
-                                            downloaded: 0,
-                                        },
-                                    ))
-                                } else {
-                                    Some((Progress::Errored, State::Finished))
-                                }
+                                let total = response.content_length();
+
+                                Some((
+                                    Progress::Started,
+                                    State::Downloading {
+                                        response,
+                                        total,
+                                        downloaded: 0,
+                                    },
+                                ))
                             }
                             Err(_) => {
                                 Some((Progress::Errored, State::Finished))
@@ -64,8 +62,11 @@ where
                         Ok(Some(chunk)) => {
                             let downloaded = downloaded + chunk.len() as u64;
 
-                            let percentage =
-                                (downloaded as f32 / total as f32) * 100.0;
+                            // The percentage is unknown if the server did
+                            // not tell us the size of the file
+                            let percentage = total.map(|total| {
+                                (downloaded as f32 / total as f32) * 100.0
+                            });
 
                             Some((
                                 Progress::Advanced(percentage),
@@ -96,7 +97,7 @@ where
 #[derive(Debug, Clone)]
 pub enum Progress {
     Started,
-    Advanced(f32),
+    Advanced(Option<f32>),
     Finished,
     Errored,
 }
@@ -105,7 +106,7 @@ pub enum State {
     Ready(String),
     Downloading {
         response: reqwest::Response,
-        total: u64,
+        total: Option<u64>,
         downloaded: u64,
     },
     Finished,
@@ -1,6 +1,6 @@
 use iced::{
     button, executor, Align, Application, Button, Column, Command, Container,
-    Element, Length, ProgressBar, Settings, Subscription, Text,
+    Element, Length, ProgressBar, Row, Settings, Spinner, Subscription, Text,
 };
 
 mod download;
@@ -12,7 +12,7 @@ pub fn main() -> iced::Result {
 #[derive(Debug)]
 enum Example {
     Idle { button: button::State },
-    Downloading { progress: f32 },
+    Downloading { progress: Option<f32> },
     Finished { button: button::State },
     Errored { button: button::State },
 }
@@ -47,14 +47,14 @@ impl Application for Example {
                 Example::Idle { .. }
                 | Example::Finished { .. }
                 | Example::Errored { .. } => {
-                    *self = Example::Downloading { progress: 0.0 };
+                    *self = Example::Downloading { progress: None };
                 }
                 _ => {}
             },
             Message::DownloadProgressed(message) => match self {
                 Example::Downloading { progress } => match message {
                     download::Progress::Started => {
-                        *progress = 0.0;
+                        *progress = None;
                     }
                     download::Progress::Advanced(percentage) => {
                         *progress = percentage;
@@ -89,13 +89,16 @@ impl Application for Example {
 
     fn view(&mut self) -> Element<Message> {
         let current_progress = match self {
-            Example::Idle { .. } => 0.0,
+            Example::Idle { .. } => Some(0.0),
             Example::Downloading { progress } => *progress,
-            Example::Finished { .. } => 100.0,
-            Example::Errored { .. } => 0.0,
+            Example::Finished { .. } => Some(100.0),
+            Example::Errored { .. } => Some(0.0),
         };
 
-        let progress_bar = ProgressBar::new(0.0..=100.0, current_progress);
+        let progress_bar = match current_progress {
+            Some(progress) => ProgressBar::new(0.0..=100.0, progress),
+            None => ProgressBar::indeterminate(),
+        };
 
         let control: Element<_> = match self {
             Example::Idle { button } => {
@@ -112,10 +115,17 @@ impl Application for Example {
                         .on_press(Message::Download),
                 )
                 .into(),
-            Example::Downloading { .. } => {
-                Text::new(format!("Downloading... {:.2}%", current_progress))
-                    .into()
-            }
+            Example::Downloading { progress } => match progress {
+                Some(progress) => {
+                    Text::new(format!("Downloading... {:.2}%", progress)).into()
+                }
+                None => Row::new()
+                    .spacing(10)
+                    .align_items(Align::Center)
+                    .push(Spinner::new().size(20))
+                    .push(Text::new("Downloading..."))
+                    .into(),
+            },
             Example::Errored { button } => Column::new()
                 .spacing(10)
                 .align_items(Align::Center)
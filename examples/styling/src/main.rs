@@ -1,7 +1,7 @@
 use iced::{
-    button, scrollable, slider, text_input, Align, Button, Checkbox, Column,
-    Container, Element, Length, ProgressBar, Radio, Row, Rule, Sandbox,
-    Scrollable, Settings, Slider, Space, Text, TextInput,
+    button, scrollable, slider, text_input, toggler, Align, Button, Checkbox,
+    Column, Container, Element, Length, ProgressBar, Radio, Row, Rule, Sandbox,
+    Scrollable, Settings, Slider, Space, Text, TextInput, Toggler,
 };
 
 pub fn main() -> iced::Result {
@@ -18,6 +18,8 @@ struct Styling {
     slider: slider::State,
     slider_value: f32,
     toggle_value: bool,
+    toggler: toggler::State,
+    toggler_value: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +29,7 @@ enum Message {
     ButtonPressed,
     SliderChanged(f32),
     CheckboxToggled(bool),
+    TogglerToggled(bool),
 }
 
 impl Sandbox for Styling {
@@ -47,6 +50,7 @@ impl Sandbox for Styling {
             Message::ButtonPressed => (),
             Message::SliderChanged(value) => self.slider_value = value,
             Message::CheckboxToggled(value) => self.toggle_value = value,
+            Message::TogglerToggled(value) => self.toggler_value = value,
         }
     }
 
@@ -108,6 +112,16 @@ impl Sandbox for Styling {
         .width(Length::Fill)
         .style(self.theme);
 
+        let toggler = Toggler::new(
+            &mut self.toggler,
+            self.toggler_value,
+            "Toggle me!",
+            Message::TogglerToggled,
+        )
+        .width(Length::Fill)
+        .label_position(toggler::LabelPosition::Left)
+        .style(self.theme);
+
         let content = Column::new()
             .spacing(20)
             .padding(20)
@@ -125,7 +139,8 @@ impl Sandbox for Styling {
                     .push(scrollable)
                     .push(Rule::vertical(38).style(self.theme))
                     .push(checkbox),
-            );
+            )
+            .push(toggler);
 
         Container::new(content)
             .width(Length::Fill)
@@ -140,7 +155,7 @@ impl Sandbox for Styling {
 mod style {
     use iced::{
         button, checkbox, container, progress_bar, radio, rule, scrollable,
-        slider, text_input,
+        slider, text_input, toggler,
     };
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -231,6 +246,15 @@ mod style {
         }
     }
 
+    impl From<Theme> for Box<dyn toggler::StyleSheet> {
+        fn from(theme: Theme) -> Self {
+            match theme {
+                Theme::Light => Default::default(),
+                Theme::Dark => dark::Toggler.into(),
+            }
+        }
+    }
+
     impl From<Theme> for Box<dyn rule::StyleSheet> {
         fn from(theme: Theme) -> Self {
             match theme {
@@ -269,7 +293,7 @@ mod style {
     mod dark {
         use iced::{
             button, checkbox, container, progress_bar, radio, rule, scrollable,
-            slider, text_input, Color,
+            slider, text_input, toggler, Color,
         };
 
         const SURFACE: Color = Color::from_rgb(
@@ -520,6 +544,35 @@ mod style {
             }
         }
 
+        pub struct Toggler;
+
+        impl toggler::StyleSheet for Toggler {
+            fn active(&self, is_active: bool) -> toggler::Style {
+                toggler::Style {
+                    background: if is_active { ACTIVE } else { SURFACE },
+                    background_border: None,
+                    foreground: if is_active { Color::WHITE } else { ACTIVE },
+                    foreground_border: None,
+                }
+            }
+
+            fn hovered(&self, is_active: bool) -> toggler::Style {
+                toggler::Style {
+                    background: if is_active { ACTIVE } else { SURFACE },
+                    background_border: None,
+                    foreground: if is_active {
+                        Color {
+                            a: 0.5,
+                            ..Color::WHITE
+                        }
+                    } else {
+                        Color { a: 0.5, ..ACTIVE }
+                    },
+                    foreground_border: None,
+                }
+            }
+        }
+
         pub struct Rule;
 
         impl rule::StyleSheet for Rule {
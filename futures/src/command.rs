@@ -1,15 +1,47 @@
-use crate::BoxFuture;
-use futures::future::{Future, FutureExt};
+use crate::BoxStream;
+use futures::future::Future;
+use futures::stream::{self, Stream, StreamExt};
+
+use std::hash::{Hash, Hasher};
 
 /// A collection of async operations.
 ///
 /// You should be able to turn a future easily into a [`Command`], either by
 /// using the `From` trait or [`Command::perform`].
 ///
+/// A [`Command`] can also produce multiple messages from a stream using
+/// [`Command::run`], be chained with other commands using [`then`], and be
+/// aborted while in-flight if it was marked as [`cancellable`].
+///
 /// [`Command`]: struct.Command.html
 /// [`Command::perform`]: #method.perform
+/// [`Command::run`]: #method.run
+/// [`then`]: #method.then
+/// [`cancellable`]: #method.cancellable
 pub struct Command<T> {
-    futures: Vec<BoxFuture<T>>,
+    actions: Vec<Action<T>>,
+}
+
+/// An action of a [`Command`].
+///
+/// [`Command`]: struct.Command.html
+pub(crate) enum Action<T> {
+    /// Runs a stream, which may be aborted if it has an id.
+    Run {
+        id: Option<u64>,
+        stream: BoxStream<T>,
+    },
+
+    /// Runs a stream that is not `Send` on the thread of the runtime, which
+    /// may be aborted if it has an id.
+    #[cfg(not(target_arch = "wasm32"))]
+    RunLocal {
+        id: Option<u64>,
+        stream: futures::stream::LocalBoxStream<'static, T>,
+    },
+
+    /// Aborts the in-flight commands with the given id.
+    Cancel(u64),
 }
 
 impl<T> Command<T> {
@@ -20,7 +52,7 @@ impl<T> Command<T> {
     /// [`Command`]: struct.Command.html
     pub fn none() -> Self {
         Self {
-            futures: Vec::new(),
+            actions: Vec::new(),
         }
     }
 
@@ -31,9 +63,29 @@ impl<T> Command<T> {
     pub fn perform<A>(
         future: impl Future<Output = T> + 'static + Send,
         f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command::run(stream::once(future), f)
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future
+    /// on the thread of the runtime.
+    ///
+    /// The future does not need to be `Send`, but the [`Command`] will only
+    /// run with an executor that supports local futures, like
+    /// [`executor::Local`]. Other executors discard it and log an error.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`executor::Local`]: executor/struct.Local.html
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn perform_local<A>(
+        future: impl Future<Output = T> + 'static,
+        f: impl Fn(T) -> A + 'static,
     ) -> Command<A> {
         Command {
-            futures: vec![Box::pin(future.map(f))],
+            actions: vec![Action::RunLocal {
+                id: None,
+                stream: stream::once(future).map(f).boxed_local(),
+            }],
         }
     }
 
@@ -44,18 +96,97 @@ impl<T> Command<T> {
     pub fn perform<A>(
         future: impl Future<Output = T> + 'static,
         f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command::run(stream::once(future), f)
+    }
+
+    /// Creates a [`Command`] that produces a message for every item of the
+    /// given stream.
+    ///
+    /// This is useful to report the progress of some long-running operation.
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run<A>(
+        stream: impl Stream<Item = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command {
+            actions: vec![Action::Run {
+                id: None,
+                stream: stream.map(f).boxed(),
+            }],
+        }
+    }
+
+    /// Creates a [`Command`] that produces a message for every item of the
+    /// given stream.
+    ///
+    /// This is useful to report the progress of some long-running operation.
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(target_arch = "wasm32")]
+    pub fn run<A>(
+        stream: impl Stream<Item = T> + 'static,
+        f: impl Fn(T) -> A + 'static,
     ) -> Command<A> {
         Command {
-            futures: vec![Box::pin(future.map(f))],
+            actions: vec![Action::Run {
+                id: None,
+                stream: stream.map(f).boxed_local(),
+            }],
+        }
+    }
+
+    /// Creates a [`Command`] that aborts the in-flight commands marked as
+    /// [`cancellable`] with the given id.
+    ///
+    /// Messages that were already produced by the aborted commands may still
+    /// be delivered.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`cancellable`]: #method.cancellable
+    pub fn cancel(id: impl Hash) -> Self {
+        Self {
+            actions: vec![Action::Cancel(hash(id))],
         }
     }
 
+    /// Marks the [`Command`] as cancellable with the given id.
+    ///
+    /// A cancellable [`Command`] can be aborted while in-flight by running
+    /// [`Command::cancel`] with the same id. Additionally, running a new
+    /// cancellable [`Command`] aborts any in-flight one with the same id.
+    /// This is useful to discard stale work, like an outdated search when
+    /// the user types again.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Command::cancel`]: #method.cancel
+    pub fn cancellable(mut self, id: impl Hash) -> Self {
+        let id = hash(id);
+
+        for action in &mut self.actions {
+            match action {
+                Action::Run { id: current, .. } => {
+                    *current = Some(id);
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                Action::RunLocal { id: current, .. } => {
+                    *current = Some(id);
+                }
+                Action::Cancel(_) => {}
+            }
+        }
+
+        self
+    }
+
     /// Applies a transformation to the result of a [`Command`].
     ///
     /// [`Command`]: struct.Command.html
     #[cfg(not(target_arch = "wasm32"))]
     pub fn map<A>(
-        mut self,
+        self,
         f: impl Fn(T) -> A + 'static + Send + Sync,
     ) -> Command<A>
     where
@@ -64,14 +195,29 @@ impl<T> Command<T> {
         let f = std::sync::Arc::new(f);
 
         Command {
-            futures: self
-                .futures
-                .drain(..)
-                .map(|future| {
-                    let f = f.clone();
-
-                    Box::pin(future.map(move |result| f(result)))
-                        as BoxFuture<A>
+            actions: self
+                .actions
+                .into_iter()
+                .map(|action| match action {
+                    Action::Run { id, stream } => {
+                        let f = f.clone();
+
+                        Action::Run {
+                            id,
+                            stream: stream.map(move |result| f(result)).boxed(),
+                        }
+                    }
+                    Action::RunLocal { id, stream } => {
+                        let f = f.clone();
+
+                        Action::RunLocal {
+                            id,
+                            stream: stream
+                                .map(move |result| f(result))
+                                .boxed_local(),
+                        }
+                    }
+                    Action::Cancel(id) => Action::Cancel(id),
                 })
                 .collect(),
         }
@@ -81,21 +227,124 @@ impl<T> Command<T> {
     ///
     /// [`Command`]: struct.Command.html
     #[cfg(target_arch = "wasm32")]
-    pub fn map<A>(mut self, f: impl Fn(T) -> A + 'static) -> Command<A>
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static) -> Command<A>
     where
         T: 'static,
     {
         let f = std::rc::Rc::new(f);
 
         Command {
-            futures: self
-                .futures
-                .drain(..)
-                .map(|future| {
-                    let f = f.clone();
-
-                    Box::pin(future.map(move |result| f(result)))
-                        as BoxFuture<A>
+            actions: self
+                .actions
+                .into_iter()
+                .map(|action| match action {
+                    Action::Run { id, stream } => {
+                        let f = f.clone();
+
+                        Action::Run {
+                            id,
+                            stream: stream
+                                .map(move |result| f(result))
+                                .boxed_local(),
+                        }
+                    }
+                    Action::Cancel(id) => Action::Cancel(id),
+                })
+                .collect(),
+        }
+    }
+
+    /// Chains a [`Command`] with another one.
+    ///
+    /// Every result of the current [`Command`] is fed to the given function,
+    /// and the [`Command`] it returns is run afterwards. The ids used to
+    /// cancel the resulting commands are ignored; mark the whole chain as
+    /// [`cancellable`] instead.
+    ///
+    /// Local commands, created with [`Command::perform_local`], can only be
+    /// chained after another local [`Command`]. Otherwise, they are
+    /// discarded.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`cancellable`]: #method.cancellable
+    /// [`Command::perform_local`]: #method.perform_local
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn then<A>(
+        self,
+        f: impl Fn(T) -> Command<A> + 'static + Send + Sync,
+    ) -> Command<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f = std::sync::Arc::new(f);
+
+        Command {
+            actions: self
+                .actions
+                .into_iter()
+                .map(|action| match action {
+                    Action::Run { id, stream } => {
+                        let f = f.clone();
+
+                        Action::Run {
+                            id,
+                            stream: stream
+                                .flat_map(move |result| f(result).into_stream())
+                                .boxed(),
+                        }
+                    }
+                    Action::RunLocal { id, stream } => {
+                        let f = f.clone();
+
+                        Action::RunLocal {
+                            id,
+                            stream: stream
+                                .flat_map(move |result| {
+                                    f(result).into_local_stream()
+                                })
+                                .boxed_local(),
+                        }
+                    }
+                    Action::Cancel(id) => Action::Cancel(id),
+                })
+                .collect(),
+        }
+    }
+
+    /// Chains a [`Command`] with another one.
+    ///
+    /// Every result of the current [`Command`] is fed to the given function,
+    /// and the [`Command`] it returns is run afterwards. The ids used to
+    /// cancel the resulting commands are ignored; mark the whole chain as
+    /// [`cancellable`] instead.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`cancellable`]: #method.cancellable
+    #[cfg(target_arch = "wasm32")]
+    pub fn then<A>(self, f: impl Fn(T) -> Command<A> + 'static) -> Command<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f = std::rc::Rc::new(f);
+
+        Command {
+            actions: self
+                .actions
+                .into_iter()
+                .map(|action| match action {
+                    Action::Run { id, stream } => {
+                        let f = f.clone();
+
+                        Action::Run {
+                            id,
+                            stream: stream
+                                .flat_map(move |result| f(result).into_stream())
+                                .boxed_local(),
+                        }
+                    }
+                    Action::Cancel(id) => Action::Cancel(id),
                 })
                 .collect(),
         }
@@ -109,19 +358,68 @@ impl<T> Command<T> {
     /// [`Command`]: struct.Command.html
     pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
         Self {
-            futures: commands
+            actions: commands
                 .into_iter()
-                .flat_map(|command| command.futures)
+                .flat_map(|command| command.actions)
                 .collect(),
         }
     }
 
-    /// Converts a [`Command`] into its underlying list of futures.
+    /// Converts a [`Command`] into its underlying list of actions.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub(crate) fn actions(self) -> Vec<Action<T>> {
+        self.actions
+    }
+
+    /// Merges the streams of a [`Command`] into a single one, discarding
+    /// any cancellation.
     ///
     /// [`Command`]: struct.Command.html
-    pub fn futures(self) -> Vec<BoxFuture<T>> {
-        self.futures
+    fn into_stream(self) -> stream::SelectAll<BoxStream<T>> {
+        stream::select_all(self.actions.into_iter().filter_map(|action| {
+            match action {
+                Action::Run { stream, .. } => Some(stream),
+                #[cfg(not(target_arch = "wasm32"))]
+                Action::RunLocal { .. } => {
+                    log::warn!(
+                        "A local command was chained after a command that \
+                        is not local. It will be discarded."
+                    );
+
+                    None
+                }
+                Action::Cancel(_) => None,
+            }
+        }))
     }
+
+    /// Merges the streams of a [`Command`] into a single local one,
+    /// discarding any cancellation.
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(not(target_arch = "wasm32"))]
+    fn into_local_stream(
+        self,
+    ) -> stream::SelectAll<futures::stream::LocalBoxStream<'static, T>>
+    where
+        T: 'static,
+    {
+        stream::select_all(self.actions.into_iter().filter_map(|action| {
+            match action {
+                Action::Run { stream, .. } => Some(stream.boxed_local()),
+                Action::RunLocal { stream, .. } => Some(stream),
+                Action::Cancel(_) => None,
+            }
+        }))
+    }
+}
+
+fn hash(id: impl Hash) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    id.hash(&mut hasher);
+
+    hasher.finish()
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -131,7 +429,10 @@ where
 {
     fn from(future: A) -> Self {
         Self {
-            futures: vec![future.boxed()],
+            actions: vec![Action::Run {
+                id: None,
+                stream: stream::once(future).boxed(),
+            }],
         }
     }
 }
@@ -143,7 +444,10 @@ where
 {
     fn from(future: A) -> Self {
         Self {
-            futures: vec![future.boxed_local()],
+            actions: vec![Action::Run {
+                id: None,
+                stream: stream::once(future).boxed_local(),
+            }],
         }
     }
 }
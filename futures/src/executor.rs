@@ -10,6 +10,12 @@ mod tokio;
 #[cfg(all(not(target_arch = "wasm32"), feature = "async-std"))]
 mod async_std;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "smol"))]
+mod smol;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod local;
+
 #[cfg(target_arch = "wasm32")]
 mod wasm_bindgen;
 
@@ -24,11 +30,20 @@ pub use self::tokio::Tokio;
 #[cfg(all(not(target_arch = "wasm32"), feature = "async-std"))]
 pub use self::async_std::AsyncStd;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "smol"))]
+pub use self::smol::Smol;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use local::Local;
+
 #[cfg(target_arch = "wasm32")]
 pub use wasm_bindgen::WasmBindgen;
 
 use futures::Future;
 
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::LocalBoxFuture;
+
 /// A type that can run futures.
 pub trait Executor: Sized {
     /// Creates a new [`Executor`].
@@ -50,6 +65,21 @@ pub trait Executor: Sized {
     #[cfg(target_arch = "wasm32")]
     fn spawn(&self, future: impl Future<Output = ()> + 'static);
 
+    /// Returns the [`LocalExecutor`] capability of the [`Executor`], if it
+    /// has one.
+    ///
+    /// Only executors that run futures on the current thread, like
+    /// [`Local`], are able to run futures that are not `Send`. By default,
+    /// it returns `None`.
+    ///
+    /// [`LocalExecutor`]: trait.LocalExecutor.html
+    /// [`Executor`]: trait.Executor.html
+    /// [`Local`]: struct.Local.html
+    #[cfg(not(target_arch = "wasm32"))]
+    fn local(&self) -> Option<&dyn LocalExecutor> {
+        None
+    }
+
     /// Runs the given closure inside the [`Executor`].
     ///
     /// Some executors, like `tokio`, require some global state to be in place
@@ -62,3 +92,28 @@ pub trait Executor: Sized {
         f()
     }
 }
+
+/// An [`Executor`] that is able to run futures that are not `Send` on the
+/// current thread.
+///
+/// Local commands, like the ones created with [`Command::perform_local`],
+/// only run in an [`Executor`] that exposes this capability through
+/// [`Executor::local`]. Other executors discard them and log an error.
+///
+/// [`Executor`]: trait.Executor.html
+/// [`Executor::local`]: trait.Executor.html#method.local
+/// [`Command::perform_local`]: ../struct.Command.html#method.perform_local
+#[cfg(not(target_arch = "wasm32"))]
+pub trait LocalExecutor {
+    /// Spawns a future that is not `Send` in the [`LocalExecutor`].
+    ///
+    /// [`LocalExecutor`]: trait.LocalExecutor.html
+    fn spawn_local(&self, future: LocalBoxFuture<'static, ()>);
+
+    /// Polls the futures spawned on the current thread until none of them
+    /// can make progress.
+    ///
+    /// The given `Waker` is notified when any of the futures can make
+    /// progress again. Then, this method should be called once more.
+    fn run_local(&self, waker: &std::task::Waker);
+}
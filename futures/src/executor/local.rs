@@ -0,0 +1,62 @@
+use crate::executor::{Executor, LocalExecutor};
+
+use futures::future::LocalBoxFuture;
+use futures::stream::{FuturesUnordered, StreamExt};
+use futures::{Future, FutureExt};
+
+use std::cell::RefCell;
+use std::task::{Context, Poll, Waker};
+
+/// A single-threaded runtime that runs futures on the thread of the event
+/// loop.
+///
+/// It is able to run futures that are not `Send`, like the ones in a
+/// [`Command::perform_local`]. Keep in mind that any blocking work in a
+/// future will block the user interface as well!
+///
+/// [`Command::perform_local`]: ../struct.Command.html#method.perform_local
+#[derive(Debug)]
+pub struct Local {
+    futures: RefCell<FuturesUnordered<LocalBoxFuture<'static, ()>>>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl Executor for Local {
+    fn new() -> Result<Self, futures::io::Error> {
+        Ok(Self {
+            futures: RefCell::new(FuturesUnordered::new()),
+            waker: RefCell::new(None),
+        })
+    }
+
+    fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+        self.spawn_local(future.boxed_local());
+    }
+
+    fn local(&self) -> Option<&dyn LocalExecutor> {
+        Some(self)
+    }
+}
+
+impl LocalExecutor for Local {
+    fn spawn_local(&self, future: LocalBoxFuture<'static, ()>) {
+        self.futures.borrow_mut().push(future);
+
+        // The event loop may be idle, so it needs to be notified to poll
+        // the new future
+        if let Some(waker) = self.waker.borrow().as_ref() {
+            waker.wake_by_ref();
+        }
+    }
+
+    fn run_local(&self, waker: &Waker) {
+        let _ = self.waker.borrow_mut().replace(waker.clone());
+
+        let mut context = Context::from_waker(waker);
+        let mut futures = self.futures.borrow_mut();
+
+        while let Poll::Ready(Some(())) = futures.poll_next_unpin(&mut context)
+        {
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Listen to changes in the file system.
+use crate::subscription::{self, Subscription};
+
+use futures::channel::mpsc;
+use std::path::{Path, PathBuf};
+
+/// A change in the file system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// A file or directory was created or moved into place.
+    Created(PathBuf),
+
+    /// The contents or metadata of a file changed.
+    Modified(PathBuf),
+
+    /// A file or directory was removed or moved away.
+    Removed(PathBuf),
+}
+
+impl Event {
+    /// Returns the path affected by the [`Event`].
+    ///
+    /// [`Event`]: enum.Event.html
+    pub fn path(&self) -> &Path {
+        match self {
+            Event::Created(path)
+            | Event::Modified(path)
+            | Event::Removed(path) => path,
+        }
+    }
+}
+
+/// Returns a [`Subscription`] that produces an [`Event`] every time the
+/// given path changes.
+///
+/// If the path is a directory, its entries are watched, but not recursively.
+///
+/// Saving a file normally produces many [`Event::Modified`] in a row. If you
+/// only care about the final result, consider combining this [`Subscription`]
+/// with a `debounce`.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+/// [`Event`]: enum.Event.html
+/// [`Event::Modified`]: enum.Event.html#variant.Modified
+pub fn watch<H: std::hash::Hasher, E>(
+    path: impl Into<PathBuf>,
+) -> Subscription<H, E, Event> {
+    Subscription::from_recipe(Watch(path.into()))
+}
+
+struct Watch(PathBuf);
+
+impl<H, E> subscription::Recipe<H, E> for Watch
+where
+    H: std::hash::Hasher,
+{
+    type Output = Event;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: futures::stream::BoxStream<'static, E>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        use futures::stream::StreamExt;
+
+        let (sender, receiver) = mpsc::unbounded();
+        let path = self.0;
+
+        // inotify is blocking, so it gets a thread of its own
+        let _ = std::thread::spawn(move || {
+            if let Err(error) = inotify::watch(&path, &sender) {
+                log::warn!("Failed to watch {}: {}", path.display(), error);
+            }
+        });
+
+        receiver.boxed()
+    }
+}
+
+mod inotify {
+    use super::Event;
+
+    use futures::channel::mpsc;
+    use nix::errno::Errno;
+    use nix::poll::{poll, PollFd, PollFlags};
+    use nix::sys::inotify::{AddWatchFlags, InitFlags, Inotify};
+    use std::os::unix::io::AsRawFd;
+    use std::path::Path;
+
+    /// How often, in milliseconds, the watcher checks whether the
+    /// subscription is still alive.
+    const POLL_TIMEOUT: i32 = 500;
+
+    pub fn watch(
+        path: &Path,
+        sender: &mpsc::UnboundedSender<Event>,
+    ) -> nix::Result<()> {
+        let inotify =
+            Inotify::init(InitFlags::IN_NONBLOCK | InitFlags::IN_CLOEXEC)?;
+
+        let result = read_events(inotify, path, sender);
+        let _ = nix::unistd::close(inotify.as_raw_fd());
+
+        result
+    }
+
+    fn read_events(
+        inotify: Inotify,
+        path: &Path,
+        sender: &mpsc::UnboundedSender<Event>,
+    ) -> nix::Result<()> {
+        let _ = inotify.add_watch(
+            path,
+            AddWatchFlags::IN_CREATE
+                | AddWatchFlags::IN_MOVED_TO
+                | AddWatchFlags::IN_MODIFY
+                | AddWatchFlags::IN_ATTRIB
+                | AddWatchFlags::IN_DELETE
+                | AddWatchFlags::IN_MOVED_FROM
+                | AddWatchFlags::IN_DELETE_SELF
+                | AddWatchFlags::IN_MOVE_SELF,
+        )?;
+
+        let mut fds = [PollFd::new(inotify.as_raw_fd(), PollFlags::POLLIN)];
+
+        // The thread stops once the subscription is dropped
+        while !sender.is_closed() {
+            match poll(&mut fds, POLL_TIMEOUT) {
+                Ok(0) | Err(Errno::EINTR) => continue,
+                Ok(_) => {}
+                Err(error) => return Err(error),
+            }
+
+            let events = match inotify.read_events() {
+                Ok(events) => events,
+                Err(Errno::EAGAIN) => continue,
+                Err(error) => return Err(error),
+            };
+
+            for event in events {
+                let path = match event.name {
+                    Some(name) => path.join(name),
+                    None => path.to_path_buf(),
+                };
+
+                let event = if event.mask.intersects(
+                    AddWatchFlags::IN_CREATE | AddWatchFlags::IN_MOVED_TO,
+                ) {
+                    Event::Created(path)
+                } else if event.mask.intersects(
+                    AddWatchFlags::IN_MODIFY | AddWatchFlags::IN_ATTRIB,
+                ) {
+                    Event::Modified(path)
+                } else if event.mask.intersects(
+                    AddWatchFlags::IN_DELETE
+                        | AddWatchFlags::IN_MOVED_FROM
+                        | AddWatchFlags::IN_DELETE_SELF
+                        | AddWatchFlags::IN_MOVE_SELF,
+                ) {
+                    Event::Removed(path)
+                } else {
+                    continue;
+                };
+
+                let _ = sender.unbounded_send(event);
+            }
+        }
+
+        Ok(())
+    }
+}
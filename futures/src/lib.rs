@@ -21,6 +21,20 @@ pub mod subscription;
 #[cfg_attr(docsrs, doc(cfg(any(feature = "tokio", feature = "async-std"))))]
 pub mod time;
 
+#[cfg(all(
+    any(feature = "tokio", feature = "async-std"),
+    not(target_arch = "wasm32")
+))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "tokio", feature = "async-std"))))]
+pub mod net;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod process;
+
+#[cfg(all(feature = "fs", target_os = "linux"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "fs")))]
+pub mod fs;
+
 pub use command::Command;
 pub use executor::Executor;
 pub use runtime::Runtime;
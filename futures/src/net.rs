@@ -0,0 +1,750 @@
+//! Talk to other processes over TCP or Unix domain sockets.
+use crate::subscription::{self, Subscription};
+use crate::{BoxStream, Command};
+
+use futures::channel::mpsc;
+use futures::io::{AsyncRead, AsyncWrite};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::Arc;
+
+/// The address of a socket.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Address {
+    /// A TCP address, like `127.0.0.1:8080`.
+    Tcp(String),
+
+    /// The path of a Unix domain socket.
+    Unix(PathBuf),
+}
+
+/// The way messages are delimited in a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Framing {
+    /// Every message is terminated by a newline (`\n`).
+    ///
+    /// The newline is not part of the received messages, and sent messages
+    /// cannot contain any. Receiving a line longer than `max_length` bytes
+    /// is an error, which closes the connection.
+    Lines {
+        /// The maximum length of a received line, in bytes.
+        max_length: u32,
+    },
+
+    /// Every message is preceded by its length, as a big-endian `u32`.
+    ///
+    /// Receiving a message longer than `max_length` bytes is an error,
+    /// which closes the connection. This protects the application from
+    /// allocating huge amounts of memory because of a single bogus length.
+    LengthPrefixed {
+        /// The maximum length of a received message, in bytes.
+        max_length: u32,
+    },
+}
+
+impl Framing {
+    /// The default maximum length of a received message: 16 MiB.
+    pub const DEFAULT_MAX_LENGTH: u32 = 16 * 1024 * 1024;
+
+    /// Creates a [`Framing::Lines`] with the [`DEFAULT_MAX_LENGTH`].
+    ///
+    /// [`Framing::Lines`]: #variant.Lines
+    /// [`DEFAULT_MAX_LENGTH`]: #associatedconstant.DEFAULT_MAX_LENGTH
+    pub fn lines() -> Self {
+        Framing::Lines {
+            max_length: Self::DEFAULT_MAX_LENGTH,
+        }
+    }
+
+    /// Creates a [`Framing::LengthPrefixed`] with the
+    /// [`DEFAULT_MAX_LENGTH`].
+    ///
+    /// [`Framing::LengthPrefixed`]: #variant.LengthPrefixed
+    /// [`DEFAULT_MAX_LENGTH`]: #associatedconstant.DEFAULT_MAX_LENGTH
+    pub fn length_prefixed() -> Self {
+        Framing::LengthPrefixed {
+            max_length: Self::DEFAULT_MAX_LENGTH,
+        }
+    }
+}
+
+/// An open connection.
+///
+/// It can be cloned and stored in your application state to send messages
+/// through the connection.
+///
+/// Dropping it does not close the connection; connections are closed when
+/// their [`Subscription`] is not returned by your application anymore.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+#[derive(Debug, Clone)]
+pub struct Connection {
+    id: usize,
+    sender: mpsc::UnboundedSender<Vec<u8>>,
+}
+
+impl Connection {
+    /// Returns the unique identifier of the [`Connection`].
+    ///
+    /// [`Connection`]: struct.Connection.html
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns a [`Command`] that sends the given message through the
+    /// [`Connection`], framing it accordingly.
+    ///
+    /// The [`Command`] does not produce any messages. Messages sent after
+    /// the [`Connection`] is closed are discarded.
+    ///
+    /// A message that cannot be framed, like one containing a newline when
+    /// using [`Framing::Lines`], produces an [`Event::Error`] and closes the
+    /// [`Connection`].
+    ///
+    /// [`Framing::Lines`]: enum.Framing.html#variant.Lines
+    /// [`Event::Error`]: enum.Event.html#variant.Error
+    ///
+    /// [`Command`]: ../struct.Command.html
+    /// [`Connection`]: struct.Connection.html
+    pub fn send<T>(&self, message: impl Into<Vec<u8>>) -> Command<T>
+    where
+        T: Send + 'static,
+    {
+        use futures::future;
+        use futures::stream::{self, StreamExt};
+
+        let sender = self.sender.clone();
+        let message = message.into();
+
+        Command::run(
+            stream::once(async move {
+                let _ = sender.unbounded_send(message);
+            })
+            .filter_map(|_| future::ready(None)),
+            |output: T| output,
+        )
+    }
+}
+
+/// An event of a connection.
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// A [`Connection`] was opened.
+    ///
+    /// [`Connection`]: struct.Connection.html
+    Connected(Connection),
+
+    /// A message was received through the [`Connection`] with the given id.
+    ///
+    /// [`Connection`]: struct.Connection.html
+    Received(usize, Vec<u8>),
+
+    /// The [`Connection`] with the given id failed, for instance because it
+    /// received a message that was too long, a line that was not valid
+    /// UTF-8 when using [`Framing::Lines`], or it was asked to send a
+    /// message that could not be framed.
+    ///
+    /// The [`Connection`] is closed afterwards.
+    ///
+    /// [`Connection`]: struct.Connection.html
+    /// [`Framing::Lines`]: enum.Framing.html#variant.Lines
+    Error(usize, Arc<std::io::Error>),
+
+    /// The [`Connection`] with the given id was closed.
+    ///
+    /// [`Connection`]: struct.Connection.html
+    Disconnected(usize),
+
+    /// The socket could not be connected or bound.
+    Failed(Arc<std::io::Error>),
+}
+
+/// Returns a [`Subscription`] that connects to the given [`Address`] and
+/// produces an [`Event`] for every framed message received.
+///
+/// The connection is closed once the [`Subscription`] is not returned by
+/// your application anymore.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+/// [`Address`]: enum.Address.html
+/// [`Event`]: enum.Event.html
+pub fn connect<H: std::hash::Hasher, E>(
+    address: Address,
+    framing: Framing,
+) -> Subscription<H, E, Event> {
+    Subscription::from_recipe(Connect { address, framing })
+}
+
+/// Returns a [`Subscription`] that listens on the given [`Address`] and
+/// produces an [`Event`] for every connection accepted and every framed
+/// message received.
+///
+/// The socket and all of its connections are closed once the
+/// [`Subscription`] is not returned by your application anymore.
+///
+/// When listening on a Unix domain socket, a stale socket file left at the
+/// path is removed before binding it, and the socket file is removed once
+/// the socket is closed.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+/// [`Address`]: enum.Address.html
+/// [`Event`]: enum.Event.html
+pub fn listen<H: std::hash::Hasher, E>(
+    address: Address,
+    framing: Framing,
+) -> Subscription<H, E, Event> {
+    Subscription::from_recipe(Listen { address, framing })
+}
+
+type Reader = Pin<Box<dyn AsyncRead + Send>>;
+type Writer = Pin<Box<dyn AsyncWrite + Send>>;
+
+struct Connect {
+    address: Address,
+    framing: Framing,
+}
+
+impl<H, E> subscription::Recipe<H, E> for Connect
+where
+    H: std::hash::Hasher,
+{
+    type Output = Event;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.address.hash(state);
+        self.framing.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: futures::stream::BoxStream<'static, E>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        use futures::stream::{self, StreamExt};
+
+        let Connect { address, framing } = *self;
+
+        stream::once(async move { backend::connect(&address).await })
+            .flat_map(move |result| match result {
+                Ok((reader, writer)) => session(reader, writer, framing),
+                Err(error) => failure(error),
+            })
+            .boxed()
+    }
+}
+
+struct Listen {
+    address: Address,
+    framing: Framing,
+}
+
+impl<H, E> subscription::Recipe<H, E> for Listen
+where
+    H: std::hash::Hasher,
+{
+    type Output = Event;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.address.hash(state);
+        self.framing.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: futures::stream::BoxStream<'static, E>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        use futures::stream::{self, StreamExt};
+
+        let Listen { address, framing } = *self;
+
+        stream::once(async move { backend::listen(&address).await })
+            .flat_map(move |result| match result {
+                Ok(connections) => connections
+                    .filter_map(|result| async move {
+                        match result {
+                            Ok(connection) => Some(connection),
+                            Err(error) => {
+                                log::warn!(
+                                    "Failed to accept connection: {}",
+                                    error
+                                );
+
+                                None
+                            }
+                        }
+                    })
+                    .map(move |(reader, writer)| {
+                        session(reader, writer, framing)
+                    })
+                    .flatten_unordered(None)
+                    .boxed(),
+                Err(error) => failure(error),
+            })
+            .boxed()
+    }
+}
+
+fn failure(error: std::io::Error) -> BoxStream<Event> {
+    use futures::stream::{self, StreamExt};
+
+    stream::once(async move { Event::Failed(Arc::new(error)) }).boxed()
+}
+
+/// Produces the events of an open connection while sending the messages of
+/// its [`Connection`].
+///
+/// [`Connection`]: struct.Connection.html
+fn session(
+    reader: Reader,
+    writer: Writer,
+    framing: Framing,
+) -> BoxStream<Event> {
+    use futures::future;
+    use futures::stream::{self, StreamExt};
+
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    let id = NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed);
+    let (sender, receiver) = mpsc::unbounded();
+
+    let events = stream::once(future::ready(Event::Connected(Connection {
+        id,
+        sender,
+    })))
+    .chain(read(reader, framing).map(move |result| match result {
+        Ok(message) => Event::Received(id, message),
+        Err(error) => Event::Error(id, Arc::new(error)),
+    }))
+    .chain(stream::once(future::ready(Event::Disconnected(id))));
+
+    let writes = stream::once(write(writer, receiver, framing)).flat_map(
+        move |result| match result {
+            Ok(()) => stream::empty().boxed(),
+            Err(error) => stream::iter(vec![
+                Some(Event::Error(id, Arc::new(error))),
+                Some(Event::Disconnected(id)),
+                None,
+            ])
+            .boxed(),
+        },
+    );
+
+    // The session ends with its events, even if some clone of the
+    // `Connection` is still alive, or when writing fails. `None` marks the
+    // end.
+    stream::select(
+        events.map(Some).chain(stream::once(future::ready(None))),
+        writes,
+    )
+    .take_while(|event| future::ready(event.is_some()))
+    .filter_map(future::ready)
+    .boxed()
+}
+
+/// Reads framed messages until the connection is closed.
+///
+/// The stream ends after the first error.
+fn read(
+    reader: Reader,
+    framing: Framing,
+) -> BoxStream<std::io::Result<Vec<u8>>> {
+    use futures::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+    use futures::stream::{self, StreamExt};
+    use std::io;
+
+    match framing {
+        Framing::Lines { max_length } => {
+            stream::unfold(
+                Some(BufReader::new(reader)),
+                move |reader| async move {
+                    let mut reader = reader?;
+                    let mut line = Vec::new();
+
+                    // A line may take one more byte than the maximum, its newline
+                    let result = match (&mut reader)
+                        .take(u64::from(max_length) + 1)
+                        .read_until(b'\n', &mut line)
+                        .await
+                    {
+                        Ok(0) => return None,
+                        Ok(length)
+                            if length > max_length as usize
+                                && !line.ends_with(b"\n") =>
+                        {
+                            Err(io::Error::new(
+                                io::ErrorKind::InvalidData,
+                                format!(
+                                    "line exceeds the maximum of {} bytes",
+                                    max_length
+                                ),
+                            ))
+                        }
+                        Ok(_) => {
+                            if line.ends_with(b"\n") {
+                                let _ = line.pop();
+
+                                if line.ends_with(b"\r") {
+                                    let _ = line.pop();
+                                }
+                            }
+
+                            String::from_utf8(line)
+                                .map(String::into_bytes)
+                                .map_err(|error| {
+                                    io::Error::new(
+                                        io::ErrorKind::InvalidData,
+                                        error,
+                                    )
+                                })
+                        }
+                        Err(error) => Err(error),
+                    };
+
+                    let reader =
+                        if result.is_ok() { Some(reader) } else { None };
+
+                    Some((result, reader))
+                },
+            )
+            .boxed()
+        }
+        Framing::LengthPrefixed { max_length } => {
+            stream::unfold(Some(reader), move |reader| async move {
+                let mut reader = reader?;
+                let mut length = [0; 4];
+
+                match reader.read_exact(&mut length).await {
+                    Ok(()) => {}
+                    // The connection was closed between messages
+                    Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => {
+                        return None
+                    }
+                    Err(error) => return Some((Err(error), None)),
+                }
+
+                let length = u32::from_be_bytes(length);
+
+                if length > max_length {
+                    let error = io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "message of {} bytes exceeds the maximum of {} bytes",
+                            length, max_length
+                        ),
+                    );
+
+                    return Some((Err(error), None));
+                }
+
+                let mut message = vec![0; length as usize];
+
+                match reader.read_exact(&mut message).await {
+                    Ok(()) => Some((Ok(message), Some(reader))),
+                    Err(error) => Some((Err(error), None)),
+                }
+            })
+            .boxed()
+        }
+    }
+}
+
+/// Writes the framed messages of a [`Connection`] until it is closed or a
+/// message cannot be written.
+///
+/// [`Connection`]: struct.Connection.html
+async fn write(
+    mut writer: Writer,
+    mut messages: mpsc::UnboundedReceiver<Vec<u8>>,
+    framing: Framing,
+) -> std::io::Result<()> {
+    use futures::io::AsyncWriteExt;
+    use futures::stream::StreamExt;
+    use std::convert::TryFrom;
+    use std::io;
+
+    while let Some(message) = messages.next().await {
+        match framing {
+            Framing::Lines { .. } => {
+                if message.contains(&b'\n') {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "a line cannot contain a newline",
+                    ));
+                }
+
+                writer.write_all(&message).await?;
+                writer.write_all(b"\n").await?;
+            }
+            Framing::LengthPrefixed { .. } => {
+                let length = u32::try_from(message.len()).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!(
+                            "message of {} bytes is too long to be framed",
+                            message.len()
+                        ),
+                    )
+                })?;
+
+                writer.write_all(&length.to_be_bytes()).await?;
+                writer.write_all(&message).await?;
+            }
+        }
+
+        writer.flush().await?;
+    }
+
+    Ok(())
+}
+
+/// The file of a Unix domain socket bound by a listener.
+///
+/// The file is removed once the listener is dropped, so the same path can
+/// be bound again later.
+#[derive(Debug)]
+struct SocketFile {
+    path: PathBuf,
+    inode: Option<(u64, u64)>,
+}
+
+impl SocketFile {
+    /// Removes the socket file at the given path if no listener is
+    /// accepting connections on it anymore.
+    ///
+    /// Files that are not sockets are left untouched.
+    fn remove_stale(path: &Path) -> std::io::Result<()> {
+        use std::os::unix::fs::FileTypeExt;
+
+        let is_socket = std::fs::symlink_metadata(path)
+            .map(|metadata| metadata.file_type().is_socket())
+            .unwrap_or(false);
+
+        if is_socket && std::os::unix::net::UnixStream::connect(path).is_err() {
+            std::fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Keeps track of the socket file just bound at the given path.
+    fn bound(path: &Path) -> Self {
+        SocketFile {
+            path: path.to_owned(),
+            inode: inode(path),
+        }
+    }
+}
+
+impl Drop for SocketFile {
+    fn drop(&mut self) {
+        // Another listener may have bound the path in the meantime
+        if self.inode.is_some() && inode(&self.path) == self.inode {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+fn inode(path: &Path) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+
+    std::fs::symlink_metadata(path)
+        .ok()
+        .map(|metadata| (metadata.dev(), metadata.ino()))
+}
+
+#[cfg(feature = "async-std")]
+mod backend {
+    use super::{Address, Reader, SocketFile, Writer};
+
+    use futures::stream::{self, BoxStream, StreamExt};
+    use std::io;
+
+    pub async fn connect(address: &Address) -> io::Result<(Reader, Writer)> {
+        match address {
+            Address::Tcp(address) => {
+                let stream =
+                    async_std::net::TcpStream::connect(address.as_str())
+                        .await?;
+
+                Ok((Box::pin(stream.clone()), Box::pin(stream)))
+            }
+            Address::Unix(path) => {
+                let stream =
+                    async_std::os::unix::net::UnixStream::connect(path).await?;
+
+                Ok((Box::pin(stream.clone()), Box::pin(stream)))
+            }
+        }
+    }
+
+    pub async fn listen(
+        address: &Address,
+    ) -> io::Result<BoxStream<'static, io::Result<(Reader, Writer)>>> {
+        match address {
+            Address::Tcp(address) => {
+                let listener =
+                    async_std::net::TcpListener::bind(address.as_str()).await?;
+
+                Ok(stream::unfold(listener, |listener| async move {
+                    let connection =
+                        listener.accept().await.map(|(stream, _)| {
+                            (
+                                Box::pin(stream.clone()) as Reader,
+                                Box::pin(stream) as Writer,
+                            )
+                        });
+
+                    Some((connection, listener))
+                })
+                .boxed())
+            }
+            Address::Unix(path) => {
+                SocketFile::remove_stale(path)?;
+
+                let listener =
+                    async_std::os::unix::net::UnixListener::bind(path).await?;
+                let file = SocketFile::bound(path);
+
+                Ok(stream::unfold(
+                    (listener, file),
+                    |(listener, file)| async move {
+                        let connection =
+                            listener.accept().await.map(|(stream, _)| {
+                                (
+                                    Box::pin(stream.clone()) as Reader,
+                                    Box::pin(stream) as Writer,
+                                )
+                            });
+
+                        Some((connection, (listener, file)))
+                    },
+                )
+                .boxed())
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "tokio", not(feature = "async-std")))]
+mod backend {
+    use super::{Address, Reader, SocketFile, Writer};
+
+    use futures::stream::{self, BoxStream, StreamExt};
+    use std::io;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    pub async fn connect(address: &Address) -> io::Result<(Reader, Writer)> {
+        match address {
+            Address::Tcp(address) => {
+                let stream =
+                    tokio::net::TcpStream::connect(address.as_str()).await?;
+
+                Ok(split(stream))
+            }
+            Address::Unix(path) => {
+                let stream = tokio::net::UnixStream::connect(path).await?;
+
+                Ok(split(stream))
+            }
+        }
+    }
+
+    pub async fn listen(
+        address: &Address,
+    ) -> io::Result<BoxStream<'static, io::Result<(Reader, Writer)>>> {
+        match address {
+            Address::Tcp(address) => {
+                let listener =
+                    tokio::net::TcpListener::bind(address.as_str()).await?;
+
+                Ok(stream::unfold(listener, |mut listener| async move {
+                    let connection = listener
+                        .accept()
+                        .await
+                        .map(|(stream, _)| split(stream));
+
+                    Some((connection, listener))
+                })
+                .boxed())
+            }
+            Address::Unix(path) => {
+                SocketFile::remove_stale(path)?;
+
+                let listener = tokio::net::UnixListener::bind(path)?;
+                let file = SocketFile::bound(path);
+
+                Ok(stream::unfold(
+                    (listener, file),
+                    |(mut listener, file)| async move {
+                        let connection = listener
+                            .accept()
+                            .await
+                            .map(|(stream, _)| split(stream));
+
+                        Some((connection, (listener, file)))
+                    },
+                )
+                .boxed())
+            }
+        }
+    }
+
+    fn split<T>(stream: T) -> (Reader, Writer)
+    where
+        T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Send + 'static,
+    {
+        let (reader, writer) = tokio::io::split(stream);
+
+        (Box::pin(Compat(reader)), Box::pin(Compat(writer)))
+    }
+
+    /// Adapts the I/O traits of `tokio` to the ones of `futures`.
+    struct Compat<T>(T);
+
+    impl<T> futures::io::AsyncRead for Compat<T>
+    where
+        T: tokio::io::AsyncRead + Unpin,
+    {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            Pin::new(&mut self.0).poll_read(cx, buf)
+        }
+    }
+
+    impl<T> futures::io::AsyncWrite for Compat<T>
+    where
+        T: tokio::io::AsyncWrite + Unpin,
+    {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            Pin::new(&mut self.0).poll_write(cx, buf)
+        }
+
+        fn poll_flush(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.0).poll_flush(cx)
+        }
+
+        fn poll_close(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.0).poll_shutdown(cx)
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! Run child processes and listen to their output.
+use crate::subscription::{self, Subscription};
+
+use futures::channel::mpsc;
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The description of a child process.
+///
+/// It is part of the identity of the [`Subscription`] returned by [`run`].
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+/// [`run`]: fn.run.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Process {
+    program: OsString,
+    args: Vec<OsString>,
+    env: Vec<(OsString, OsString)>,
+    current_dir: Option<PathBuf>,
+}
+
+impl Process {
+    /// Creates a new [`Process`] that runs the given program.
+    ///
+    /// [`Process`]: struct.Process.html
+    pub fn new(program: impl AsRef<OsStr>) -> Self {
+        Process {
+            program: program.as_ref().to_owned(),
+            args: Vec::new(),
+            env: Vec::new(),
+            current_dir: None,
+        }
+    }
+
+    /// Adds an argument to the [`Process`].
+    ///
+    /// [`Process`]: struct.Process.html
+    pub fn arg(mut self, arg: impl AsRef<OsStr>) -> Self {
+        self.args.push(arg.as_ref().to_owned());
+        self
+    }
+
+    /// Adds multiple arguments to the [`Process`].
+    ///
+    /// [`Process`]: struct.Process.html
+    pub fn args(
+        mut self,
+        args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+    ) -> Self {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.as_ref().to_owned()));
+        self
+    }
+
+    /// Sets an environment variable of the [`Process`].
+    ///
+    /// [`Process`]: struct.Process.html
+    pub fn env(
+        mut self,
+        key: impl AsRef<OsStr>,
+        value: impl AsRef<OsStr>,
+    ) -> Self {
+        self.env
+            .push((key.as_ref().to_owned(), value.as_ref().to_owned()));
+        self
+    }
+
+    /// Sets the working directory of the [`Process`].
+    ///
+    /// [`Process`]: struct.Process.html
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    fn command(&self) -> std::process::Command {
+        use std::process::Stdio;
+
+        let mut command = std::process::Command::new(&self.program);
+
+        let _ = command
+            .args(&self.args)
+            .envs(self.env.iter().map(|(key, value)| (key, value)))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        if let Some(dir) = &self.current_dir {
+            let _ = command.current_dir(dir);
+        }
+
+        command
+    }
+}
+
+/// The output of a running [`Process`].
+///
+/// [`Process`]: struct.Process.html
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// A line was written to the standard output.
+    Stdout(String),
+
+    /// A line was written to the standard error.
+    Stderr(String),
+
+    /// The process exited.
+    ///
+    /// Its output is usually delivered before. However, output that stays
+    /// open after the process exits, like the one inherited by its own
+    /// children, may still produce lines later.
+    Exited(std::process::ExitStatus),
+
+    /// The process could not be spawned or waited for.
+    Failed(Arc<std::io::Error>),
+}
+
+/// Returns a [`Subscription`] that runs the given [`Process`] and produces
+/// an [`Event`] for every line of its output and for its exit.
+///
+/// The [`Process`] is killed once the [`Subscription`] is not returned by
+/// your application anymore. Returning a [`Subscription`] for the same
+/// [`Process`] after it has exited will not run it again; use
+/// [`Subscription::with`] to tell different runs apart.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+/// [`Subscription::with`]: ../subscription/struct.Subscription.html#method.with
+/// [`Process`]: struct.Process.html
+/// [`Event`]: enum.Event.html
+pub fn run<H: std::hash::Hasher, E>(
+    process: Process,
+) -> Subscription<H, E, Event> {
+    Subscription::from_recipe(Run(process))
+}
+
+struct Run(Process);
+
+impl<H, E> subscription::Recipe<H, E> for Run
+where
+    H: std::hash::Hasher,
+{
+    type Output = Event;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: futures::stream::BoxStream<'static, E>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        use futures::stream::StreamExt;
+
+        let (sender, receiver) = mpsc::unbounded();
+
+        match self.0.command().spawn() {
+            Ok(child) => {
+                let _ = std::thread::spawn(move || supervise(child, sender));
+            }
+            Err(error) => {
+                let _ = sender.unbounded_send(Event::Failed(Arc::new(error)));
+            }
+        }
+
+        receiver.boxed()
+    }
+}
+
+/// How often the supervisor checks whether the process has exited or the
+/// subscription was dropped.
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
+/// How long the supervisor waits for the remaining output of a process
+/// after it exits.
+///
+/// A process may leave its output open after exiting, for instance when it
+/// spawns children of its own that inherit it. Their output is still
+/// forwarded, but it may arrive after the [`Event::Exited`].
+///
+/// [`Event::Exited`]: enum.Event.html#variant.Exited
+const OUTPUT_GRACE_PERIOD: std::time::Duration =
+    std::time::Duration::from_millis(500);
+
+fn supervise(
+    mut child: std::process::Child,
+    sender: mpsc::UnboundedSender<Event>,
+) {
+    let (finished, readers_finished) = std::sync::mpsc::channel();
+
+    let readers = vec![
+        child.stdout.take().map(|stdout| {
+            forward_lines(
+                stdout,
+                sender.clone(),
+                finished.clone(),
+                Event::Stdout,
+            )
+        }),
+        child.stderr.take().map(|stderr| {
+            forward_lines(
+                stderr,
+                sender.clone(),
+                finished.clone(),
+                Event::Stderr,
+            )
+        }),
+    ];
+
+    let status = loop {
+        if sender.is_closed() {
+            let _ = child.kill();
+            let _ = child.wait();
+
+            return;
+        }
+
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => std::thread::sleep(POLL_INTERVAL),
+            Err(error) => {
+                let _ = child.kill();
+                let _ = sender.unbounded_send(Event::Failed(Arc::new(error)));
+
+                return;
+            }
+        }
+    };
+
+    // Deliver the remaining output before reporting the exit, as long as it
+    // does not take too long. The readers are detached otherwise.
+    let deadline = std::time::Instant::now() + OUTPUT_GRACE_PERIOD;
+
+    for _ in readers.into_iter().flatten() {
+        let timeout =
+            deadline.saturating_duration_since(std::time::Instant::now());
+
+        if readers_finished.recv_timeout(timeout).is_err() {
+            break;
+        }
+    }
+
+    let _ = sender.unbounded_send(Event::Exited(status));
+}
+
+fn forward_lines(
+    output: impl std::io::Read + Send + 'static,
+    sender: mpsc::UnboundedSender<Event>,
+    finished: std::sync::mpsc::Sender<()>,
+    to_event: fn(String) -> Event,
+) -> std::thread::JoinHandle<()> {
+    use std::io::BufRead;
+
+    std::thread::spawn(move || {
+        let mut output = std::io::BufReader::new(output);
+        let mut line = Vec::new();
+
+        // Lines are read as bytes, so invalid UTF-8 does not stop the output
+        // from being drained
+        loop {
+            line.clear();
+
+            match output.read_until(b'\n', &mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    if line.ends_with(b"\n") {
+                        let _ = line.pop();
+
+                        if line.ends_with(b"\r") {
+                            let _ = line.pop();
+                        }
+                    }
+
+                    let line = String::from_utf8_lossy(&line).into_owned();
+
+                    if sender.unbounded_send(to_event(line)).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let _ = finished.send(());
+    })
+}
@@ -1,8 +1,16 @@
 //! Run commands and keep track of subscriptions.
+use crate::command::Action;
 use crate::{subscription, Command, Executor, Subscription};
 
-use futures::{channel::mpsc, Sink};
+use futures::{
+    channel::{mpsc, oneshot},
+    future::{Future, Shared},
+    Sink, Stream,
+};
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::Arc;
 
 /// A batteries-included runtime of commands and subscriptions.
 ///
@@ -18,6 +26,8 @@ pub struct Runtime<Hasher, Event, Executor, Sender, Message> {
     executor: Executor,
     sender: Sender,
     subscriptions: subscription::Tracker<Hasher, Event>,
+    cancellables: HashMap<u64, oneshot::Sender<()>>,
+    pending_commands: Arc<AtomicUsize>,
     _message: PhantomData<Message>,
 }
 
@@ -43,6 +53,8 @@ where
             executor,
             sender,
             subscriptions: subscription::Tracker::new(),
+            cancellables: HashMap::new(),
+            pending_commands: Arc::new(AtomicUsize::new(0)),
             _message: PhantomData,
         }
     }
@@ -60,26 +72,140 @@ where
 
     /// Spawns a [`Command`] in the [`Runtime`].
     ///
-    /// The resulting `Message` will be forwarded to the `Sender` of the
+    /// The resulting messages will be forwarded to the `Sender` of the
     /// [`Runtime`].
     ///
+    /// Spawning a cancellable [`Command`] aborts any in-flight [`Command`]
+    /// with the same id.
+    ///
     /// [`Command`]: struct.Command.html
     /// [`Runtime`]: struct.Runtime.html
     pub fn spawn(&mut self, command: Command<Message>) {
-        use futures::{FutureExt, SinkExt};
+        use futures::FutureExt;
 
-        let futures = command.futures();
+        // Forget about the cancellable commands that already finished
+        self.cancellables.retain(|_, cancel| !cancel.is_canceled());
 
-        for future in futures {
-            let mut sender = self.sender.clone();
+        // All the actions of the command with the same id share a single
+        // cancellation handle
+        let mut handles = HashMap::new();
+
+        for action in command.actions() {
+            match action {
+                Action::Run { id, stream } => {
+                    let future = forward(
+                        stream,
+                        self.sender.clone(),
+                        &self.pending_commands,
+                    );
+
+                    if let Some(id) = id {
+                        let cancelled = self.cancellation(id, &mut handles);
+
+                        self.executor.spawn(
+                            futures::future::select(cancelled, future)
+                                .map(|_| ()),
+                        );
+                    } else {
+                        self.executor.spawn(future);
+                    }
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                Action::RunLocal { id, stream } => {
+                    if self.executor.local().is_none() {
+                        log::error!(
+                            "The executor cannot run local commands. Use \
+                            `executor::Local` to run them on the current \
+                            thread."
+                        );
+
+                        continue;
+                    }
+
+                    let future = forward(
+                        stream,
+                        self.sender.clone(),
+                        &self.pending_commands,
+                    );
 
-            let future = future.then(|message| async move {
-                let _ = sender.send(message).await;
+                    if let Some(id) = id {
+                        let cancelled = self.cancellation(id, &mut handles);
+
+                        self.spawn_local(
+                            futures::future::select(cancelled, future)
+                                .map(|_| ()),
+                        );
+                    } else {
+                        self.spawn_local(future);
+                    }
+                }
+                Action::Cancel(id) => {
+                    let _ = handles.remove(&id);
+                    let _ = self.cancellables.remove(&id);
+                }
+            }
+        }
+    }
+
+    /// Returns true if some [`Command`] spawned in the [`Runtime`] has not
+    /// finished yet.
+    ///
+    /// Cancelled commands count as finished once their futures are dropped.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Runtime`]: struct.Runtime.html
+    pub fn has_pending_commands(&self) -> bool {
+        self.pending_commands.load(atomic::Ordering::SeqCst) > 0
+    }
+
+    /// Returns the cancellation handle of the command being spawned with
+    /// the given id.
+    ///
+    /// The first action with the id replaces the previous handle, which
+    /// aborts the in-flight command that owned it.
+    fn cancellation(
+        &mut self,
+        id: u64,
+        handles: &mut HashMap<u64, Shared<oneshot::Receiver<()>>>,
+    ) -> Shared<oneshot::Receiver<()>> {
+        use futures::FutureExt;
+
+        let cancellables = &mut self.cancellables;
+
+        handles
+            .entry(id)
+            .or_insert_with(|| {
+                let (cancel, cancelled) = oneshot::channel();
+                let _ = cancellables.insert(id, cancel);
+
+                cancelled.shared()
+            })
+            .clone()
+    }
+
+    /// Polls the futures that the [`Runtime`] spawned on the current thread.
+    ///
+    /// The given `Waker` is notified when they can make progress again.
+    /// See [`LocalExecutor::run_local`] to learn more.
+    ///
+    /// It does nothing if the executor of the [`Runtime`] cannot run local
+    /// futures.
+    ///
+    /// [`Runtime`]: struct.Runtime.html
+    /// [`LocalExecutor::run_local`]: executor/trait.LocalExecutor.html#tymethod.run_local
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run_local(&self, waker: &std::task::Waker) {
+        if let Some(local) = self.executor.local() {
+            local.run_local(waker);
+        }
+    }
 
-                ()
-            });
+    #[cfg(not(target_arch = "wasm32"))]
+    fn spawn_local(&self, future: impl Future<Output = ()> + 'static) {
+        use futures::FutureExt;
 
-            self.executor.spawn(future);
+        if let Some(local) = self.executor.local() {
+            local.spawn_local(future.boxed_local());
         }
     }
 
@@ -122,3 +248,44 @@ where
         self.subscriptions.broadcast(event);
     }
 }
+
+/// Forwards the messages of a stream to the given `Sender`, keeping the
+/// [`Command`] that owns the stream pending meanwhile.
+///
+/// [`Command`]: struct.Command.html
+fn forward<Message, Sender>(
+    stream: impl Stream<Item = Message>,
+    sender: Sender,
+    pending_commands: &Arc<AtomicUsize>,
+) -> impl Future<Output = ()>
+where
+    Sender: Sink<Message, Error = mpsc::SendError>,
+{
+    use futures::{FutureExt, StreamExt};
+
+    let pending = Pending::new(pending_commands);
+
+    // The command stops being pending when its future is dropped, even if it
+    // is cancelled
+    stream.map(Ok).forward(sender).map(move |_| drop(pending))
+}
+
+/// A guard that keeps a [`Command`] pending until it is dropped.
+///
+/// [`Command`]: struct.Command.html
+#[derive(Debug)]
+struct Pending(Arc<AtomicUsize>);
+
+impl Pending {
+    fn new(count: &Arc<AtomicUsize>) -> Self {
+        let _ = count.fetch_add(1, atomic::Ordering::SeqCst);
+
+        Pending(count.clone())
+    }
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        let _ = self.0.fetch_sub(1, atomic::Ordering::SeqCst);
+    }
+}
@@ -1,6 +1,9 @@
 //! Listen to external events in your application.
 mod tracker;
 
+pub mod channel;
+
+pub use channel::channel;
 pub use tracker::Tracker;
 
 use crate::BoxStream;
@@ -126,6 +129,104 @@ where
                 .collect(),
         }
     }
+
+    /// Transforms the [`Subscription`] output with the given function,
+    /// discarding the outputs for which it returns `None`.
+    ///
+    /// [`Subscription`]: struct.Subscription.html
+    pub fn filter_map<A>(
+        mut self,
+        f: impl Fn(O) -> Option<A> + Send + Sync + 'static,
+    ) -> Subscription<H, E, A>
+    where
+        H: 'static,
+        E: 'static,
+        O: 'static,
+        A: Send + 'static,
+    {
+        let function = std::sync::Arc::new(f);
+
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(FilterMap::new(recipe, function.clone()))
+                        as Box<dyn Recipe<H, E, Output = A>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Limits the rate of the [`Subscription`] to at most one output per
+    /// `duration`.
+    ///
+    /// The first output is produced right away. Any outputs received during
+    /// the following `duration` are discarded, except for the latest one,
+    /// which is produced once the `duration` has elapsed.
+    ///
+    /// This is useful to listen to high-frequency sources, like mouse
+    /// movements, without flooding your application with messages.
+    ///
+    /// [`Subscription`]: struct.Subscription.html
+    #[cfg(all(
+        any(feature = "tokio", feature = "async-std"),
+        not(target_arch = "wasm32")
+    ))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "tokio", feature = "async-std"))))]
+    pub fn throttle(
+        mut self,
+        duration: std::time::Duration,
+    ) -> Subscription<H, E, O>
+    where
+        H: 'static,
+        E: 'static,
+        O: Send + 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(crate::time::Throttle::new(recipe, duration))
+                        as Box<dyn Recipe<H, E, Output = O>>
+                })
+                .collect(),
+        }
+    }
+
+    /// Delays the outputs of the [`Subscription`] until no new outputs have
+    /// been received for the given `duration`.
+    ///
+    /// Only the latest output of every burst is produced. This is useful to
+    /// react to sources that produce many outputs at once, like file changes.
+    ///
+    /// [`Subscription`]: struct.Subscription.html
+    #[cfg(all(
+        any(feature = "tokio", feature = "async-std"),
+        not(target_arch = "wasm32")
+    ))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "tokio", feature = "async-std"))))]
+    pub fn debounce(
+        mut self,
+        duration: std::time::Duration,
+    ) -> Subscription<H, E, O>
+    where
+        H: 'static,
+        E: 'static,
+        O: Send + 'static,
+    {
+        Subscription {
+            recipes: self
+                .recipes
+                .drain(..)
+                .map(|recipe| {
+                    Box::new(crate::time::Debounce::new(recipe, duration))
+                        as Box<dyn Recipe<H, E, Output = O>>
+                })
+                .collect(),
+        }
+    }
 }
 
 impl<I, O, H> std::fmt::Debug for Subscription<I, O, H> {
@@ -226,6 +327,50 @@ where
     }
 }
 
+struct FilterMap<Hasher, Event, A, B> {
+    recipe: Box<dyn Recipe<Hasher, Event, Output = A>>,
+    mapper: std::sync::Arc<dyn Fn(A) -> Option<B> + Send + Sync>,
+}
+
+impl<H, E, A, B> FilterMap<H, E, A, B> {
+    fn new(
+        recipe: Box<dyn Recipe<H, E, Output = A>>,
+        mapper: std::sync::Arc<dyn Fn(A) -> Option<B> + Send + Sync + 'static>,
+    ) -> Self {
+        FilterMap { recipe, mapper }
+    }
+}
+
+impl<H, E, A, B> Recipe<H, E> for FilterMap<H, E, A, B>
+where
+    A: 'static,
+    B: Send + 'static,
+    H: std::hash::Hasher,
+{
+    type Output = B;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        std::any::TypeId::of::<B>().hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: BoxStream<E>) -> BoxStream<Self::Output> {
+        use futures::StreamExt;
+
+        let mapper = self.mapper;
+
+        Box::pin(
+            self.recipe.stream(input).filter_map(move |element| {
+                futures::future::ready(mapper(element))
+            }),
+        )
+    }
+}
+
 struct With<Hasher, Event, A, B> {
     recipe: Box<dyn Recipe<Hasher, Event, Output = A>>,
     value: B,
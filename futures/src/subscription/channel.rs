@@ -0,0 +1,91 @@
+//! Push messages into an application from anywhere.
+use crate::subscription::{Recipe, Subscription};
+use crate::BoxStream;
+
+use futures::channel::mpsc;
+
+/// The sending half of a [`channel`] subscription.
+///
+/// It can be cloned and moved to other threads freely. Sending a value
+/// fails once the [`channel`] subscription is no longer being tracked.
+///
+/// [`channel`]: fn.channel.html
+pub type Sender<T> = mpsc::UnboundedSender<T>;
+
+/// The output of a [`channel`] subscription.
+///
+/// [`channel`]: fn.channel.html
+#[derive(Debug, Clone)]
+pub enum Output<T> {
+    /// The channel was opened.
+    ///
+    /// The [`Sender`] can be used to push values into the subscription.
+    ///
+    /// [`Sender`]: type.Sender.html
+    Ready(Sender<T>),
+
+    /// A value was sent through the [`Sender`] of the channel.
+    ///
+    /// [`Sender`]: type.Sender.html
+    Received(T),
+}
+
+/// Returns a [`Subscription`] that opens a channel and produces every value
+/// sent through it.
+///
+/// The first output of the [`Subscription`] is [`Output::Ready`], which
+/// contains the [`Sender`] of the channel. You can store it in your
+/// application state or hand it to a background thread or library callback.
+///
+/// The `id` identifies the [`Subscription`]. The channel stays open as long
+/// as you keep returning a [`Subscription`] with the same `id`.
+///
+/// [`Subscription`]: ../struct.Subscription.html
+/// [`Output::Ready`]: enum.Output.html#variant.Ready
+/// [`Sender`]: type.Sender.html
+pub fn channel<H, E, I, T>(id: I) -> Subscription<H, E, Output<T>>
+where
+    H: std::hash::Hasher,
+    I: std::hash::Hash + 'static,
+    T: Send + 'static,
+{
+    Subscription::from_recipe(Channel {
+        id,
+        _value: std::marker::PhantomData,
+    })
+}
+
+struct Channel<I, T> {
+    id: I,
+    _value: std::marker::PhantomData<T>,
+}
+
+impl<H, E, I, T> Recipe<H, E> for Channel<I, T>
+where
+    H: std::hash::Hasher,
+    I: std::hash::Hash + 'static,
+    T: Send + 'static,
+{
+    type Output = Output<T>;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.id.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<E>,
+    ) -> BoxStream<Self::Output> {
+        use futures::stream::{self, StreamExt};
+
+        let (sender, receiver) = mpsc::unbounded();
+
+        Box::pin(
+            stream::once(async move { Output::Ready(sender) })
+                .chain(receiver.map(Output::Received)),
+        )
+    }
+}
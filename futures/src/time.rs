@@ -68,3 +68,206 @@ where
             .boxed()
     }
 }
+
+/// Waits for the given `duration` using the enabled timer.
+fn sleep(duration: std::time::Duration) -> crate::BoxFuture<()> {
+    #[cfg(feature = "async-std")]
+    {
+        Box::pin(async_std::task::sleep(duration))
+    }
+
+    #[cfg(all(feature = "tokio", not(feature = "async-std")))]
+    {
+        Box::pin(tokio::time::delay_for(duration))
+    }
+}
+
+/// The outputs of a recipe that have yet to be produced.
+struct Pending<Output> {
+    input: crate::BoxStream<Output>,
+    latest: Option<Output>,
+    last_output: Option<std::time::Instant>,
+    is_finished: bool,
+}
+
+impl<Output> Pending<Output> {
+    fn new(input: crate::BoxStream<Output>) -> Self {
+        Pending {
+            input,
+            latest: None,
+            last_output: None,
+            is_finished: false,
+        }
+    }
+}
+
+pub(crate) struct Throttle<H, E, O> {
+    recipe: Box<dyn subscription::Recipe<H, E, Output = O>>,
+    duration: std::time::Duration,
+}
+
+impl<H, E, O> Throttle<H, E, O> {
+    pub(crate) fn new(
+        recipe: Box<dyn subscription::Recipe<H, E, Output = O>>,
+        duration: std::time::Duration,
+    ) -> Self {
+        Throttle { recipe, duration }
+    }
+}
+
+impl<H, E, O> subscription::Recipe<H, E> for Throttle<H, E, O>
+where
+    H: std::hash::Hasher,
+    O: Send + 'static,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: futures::stream::BoxStream<'static, E>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        use futures::future::{self, Either};
+        use futures::stream::{self, StreamExt};
+        use std::time::Instant;
+
+        let duration = self.duration;
+        let pending = Pending::new(self.recipe.stream(input));
+
+        stream::unfold(pending, move |mut pending| async move {
+            loop {
+                if pending.is_finished {
+                    return None;
+                }
+
+                if pending.latest.is_some() {
+                    // Keep the latest output until the window closes
+                    let elapsed = pending
+                        .last_output
+                        .map(|last_output| last_output.elapsed())
+                        .unwrap_or(duration);
+
+                    let timer = sleep(
+                        duration.checked_sub(elapsed).unwrap_or_default(),
+                    );
+
+                    match future::select(pending.input.next(), timer).await {
+                        Either::Left((Some(output), _)) => {
+                            pending.latest = Some(output);
+                        }
+                        Either::Left((None, _)) => {
+                            pending.is_finished = true;
+
+                            let output = pending.latest.take()?;
+                            return Some((output, pending));
+                        }
+                        Either::Right(_) => {
+                            pending.last_output = Some(Instant::now());
+
+                            let output = pending.latest.take()?;
+                            return Some((output, pending));
+                        }
+                    }
+                } else {
+                    let output = pending.input.next().await?;
+
+                    let is_ready = pending
+                        .last_output
+                        .map(|last_output| last_output.elapsed() >= duration)
+                        .unwrap_or(true);
+
+                    if is_ready {
+                        pending.last_output = Some(Instant::now());
+
+                        return Some((output, pending));
+                    }
+
+                    pending.latest = Some(output);
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+pub(crate) struct Debounce<H, E, O> {
+    recipe: Box<dyn subscription::Recipe<H, E, Output = O>>,
+    duration: std::time::Duration,
+}
+
+impl<H, E, O> Debounce<H, E, O> {
+    pub(crate) fn new(
+        recipe: Box<dyn subscription::Recipe<H, E, Output = O>>,
+        duration: std::time::Duration,
+    ) -> Self {
+        Debounce { recipe, duration }
+    }
+}
+
+impl<H, E, O> subscription::Recipe<H, E> for Debounce<H, E, O>
+where
+    H: std::hash::Hasher,
+    O: Send + 'static,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        input: futures::stream::BoxStream<'static, E>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        use futures::future::{self, Either};
+        use futures::stream::{self, StreamExt};
+
+        let duration = self.duration;
+        let pending = Pending::new(self.recipe.stream(input));
+
+        stream::unfold(pending, move |mut pending| async move {
+            loop {
+                if pending.is_finished {
+                    return None;
+                }
+
+                if pending.latest.is_none() {
+                    pending.latest = Some(pending.input.next().await?);
+                }
+
+                // Every new output restarts the timer
+                match future::select(pending.input.next(), sleep(duration))
+                    .await
+                {
+                    Either::Left((Some(output), _)) => {
+                        pending.latest = Some(output);
+                    }
+                    Either::Left((None, _)) => {
+                        pending.is_finished = true;
+
+                        let output = pending.latest.take()?;
+                        return Some((output, pending));
+                    }
+                    Either::Right(_) => {
+                        let output = pending.latest.take()?;
+                        return Some((output, pending));
+                    }
+                }
+            }
+        })
+        .boxed()
+    }
+}
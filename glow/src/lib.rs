@@ -28,7 +28,7 @@ pub use widget::*;
 
 pub use iced_graphics::{Error, Viewport};
 pub use iced_native::{
-    Background, Color, Command, HorizontalAlignment, Length, Vector,
+    Background, Color, Command, HorizontalAlignment, Length, Padding, Vector,
     VerticalAlignment,
 };
 
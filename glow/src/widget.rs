@@ -12,14 +12,19 @@ use crate::Renderer;
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod grid;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod responsive;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod spinner;
+pub mod stack;
 pub mod text_input;
+pub mod toggler;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -28,6 +33,8 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -36,13 +43,21 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use toggler::Toggler;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -62,3 +77,7 @@ pub type Row<'a, Message> = iced_native::Row<'a, Message, Renderer>;
 
 /// A paragraph of text.
 pub type Text = iced_native::Text<Renderer>;
+
+/// A container that distributes its contents in lines that wrap when
+/// running out of space.
+pub type Wrap<'a, Message> = iced_native::Wrap<'a, Message, Renderer>;
@@ -0,0 +1,12 @@
+//! Lay out content in rows and columns.
+use crate::Renderer;
+
+/// A container that lays out its contents in rows and columns.
+///
+/// This is an alias of an `iced_native` grid with an `iced_glow::Renderer`.
+pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Renderer>;
+
+/// A cell of a [`Grid`], holding some content.
+///
+/// [`Grid`]: type.Grid.html
+pub type Cell<'a, Message> = iced_native::grid::Cell<'a, Message, Renderer>;
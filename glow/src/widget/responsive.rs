@@ -0,0 +1,11 @@
+//! Build content from the space available to it.
+use crate::Renderer;
+
+pub use iced_native::responsive::State;
+
+/// A widget that builds its content from the size available to it.
+///
+/// This is an alias of an `iced_native` responsive widget with an
+/// `iced_glow::Renderer`.
+pub type Responsive<'a, Message> =
+    iced_native::Responsive<'a, Message, Renderer>;
@@ -0,0 +1,12 @@
+//! Display content on top of other content.
+use crate::Renderer;
+
+/// A container that displays its contents on top of each other.
+///
+/// This is an alias of an `iced_native` stack with an `iced_glow::Renderer`.
+pub type Stack<'a, Message> = iced_native::Stack<'a, Message, Renderer>;
+
+/// A layer of a [`Stack`], holding some content.
+///
+/// [`Stack`]: type.Stack.html
+pub type Layer<'a, Message> = iced_native::stack::Layer<'a, Message, Renderer>;
@@ -4,7 +4,7 @@ use iced_graphics::window;
 use iced_graphics::Viewport;
 use iced_winit::application;
 use iced_winit::conversion;
-use iced_winit::{Clipboard, Debug, Proxy, Settings};
+use iced_winit::{Clipboard, Debug, Proxy, Recording, Settings};
 
 pub use iced_winit::Application;
 pub use iced_winit::{program, Program};
@@ -32,9 +32,11 @@ where
     debug.startup_started();
 
     let event_loop = EventLoop::with_user_event();
+    let proxy = Proxy::new(event_loop.create_proxy());
+    let waker = proxy.waker();
+
     let mut runtime = {
         let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
-        let proxy = Proxy::new(event_loop.create_proxy());
 
         Runtime::new(executor, proxy)
     };
@@ -45,6 +47,7 @@ where
 
     let subscription = application.subscription();
     runtime.track(subscription);
+    runtime.run_local(&waker);
 
     let mut title = application.title();
     let mut mode = application.mode();
@@ -82,7 +85,9 @@ where
     let clipboard = Clipboard::new(&context.window());
     let mut cursor_position = glutin::dpi::PhysicalPosition::new(-1.0, -1.0);
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut ime_area = None;
     let mut modifiers = glutin::event::ModifiersState::default();
+    let mut primary_finger = None;
 
     let physical_size = context.window().inner_size();
     let mut viewport = Viewport::with_physical_size(
@@ -90,6 +95,7 @@ where
         context.window().scale_factor() * scale_factor,
     );
     let mut resized = false;
+    let mut is_exiting = false;
 
     #[allow(unsafe_code)]
     let (mut compositor, mut renderer) = unsafe {
@@ -107,9 +113,27 @@ where
     );
     debug.startup_finished();
 
+    let mut recording =
+        Recording::from_environment(context.window(), &viewport);
+
     event_loop.run(move |event, _, control_flow| match event {
         event::Event::MainEventsCleared => {
-            if state.is_queue_empty() {
+            for event in recording.replay(
+                context.window(),
+                &mut viewport,
+                &mut resized,
+                &mut cursor_position,
+            ) {
+                state.queue_event(event.clone());
+                runtime.broadcast(event);
+            }
+
+            let pending_update = if let Some(nd) = state.next_draw() {
+                nd < std::time::Instant::now()
+            } else {
+                false
+            };
+            if state.is_queue_empty() && !pending_update {
                 return;
             }
 
@@ -126,6 +150,21 @@ where
                 )
             });
 
+            // Place the candidate window of input methods next to the text
+            // cursor of the focused text input
+            let new_ime_area =
+                iced_native::Renderer::take_ime_area(&mut renderer);
+
+            if new_ime_area != ime_area {
+                if let Some(area) = new_ime_area {
+                    context.window().set_ime_position(
+                        conversion::ime_position(area, viewport.scale_factor()),
+                    );
+                }
+
+                ime_area = new_ime_area;
+            }
+
             // If the application was updated
             if let Some(command) = command {
                 runtime.spawn(command);
@@ -135,6 +174,7 @@ where
                 // Update subscriptions
                 let subscription = program.subscription();
                 runtime.track(subscription);
+                runtime.run_local(&waker);
 
                 // Update window title
                 let new_title = program.title();
@@ -189,13 +229,25 @@ where
 
                     scale_factor = new_scale_factor;
                 }
+
+                // Leave the event loop once the application is done and its
+                // pending commands finish
+                if state.program().should_exit() {
+                    is_exiting = true;
+                }
             }
 
+            if let Some(next_draw) = state.next_draw() {
+                *control_flow = ControlFlow::WaitUntil(next_draw);
+            };
             context.window().request_redraw();
         }
-        event::Event::UserEvent(message) => {
+        event::Event::UserEvent(Some(message)) => {
             state.queue_message(message);
         }
+        event::Event::UserEvent(None) => {
+            runtime.run_local(&waker);
+        }
         event::Event::RedrawRequested(_) => {
             debug.render_started();
 
@@ -232,8 +284,9 @@ where
                 mouse_interaction = new_mouse_interaction;
             }
 
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
+            if let Some(next_draw) = state.next_draw() {
+                *control_flow = ControlFlow::WaitUntil(next_draw);
+            };
         }
         event::Event::WindowEvent {
             event: window_event,
@@ -255,13 +308,102 @@ where
                 &window_event,
                 viewport.scale_factor(),
                 modifiers,
-            ) {
+            )
+            .filter(|_| !recording.is_replaying())
+            {
+                let emulated_events = match event {
+                    iced_native::Event::Touch(touch_event) => {
+                        conversion::touch_to_mouse(
+                            touch_event,
+                            &mut primary_finger,
+                        )
+                    }
+                    _ => Vec::new(),
+                };
+
+                // A new scale factor also changes the logical size of the
+                // window
+                let resized_event = match event {
+                    iced_native::Event::Window(
+                        iced_native::window::Event::ScaleFactorChanged {
+                            width,
+                            height,
+                            ..
+                        },
+                    ) => Some(iced_native::Event::Window(
+                        iced_native::window::Event::Resized { width, height },
+                    )),
+                    _ => None,
+                };
+
+                recording.record(&event);
+
                 state.queue_event(event.clone());
                 runtime.broadcast(event);
+
+                if let Some(event) = resized_event {
+                    recording.record(&event);
+                    state.queue_event(event.clone());
+                    runtime.broadcast(event);
+                }
+
+                for mouse_event in emulated_events {
+                    match mouse_event {
+                        mouse::Event::CursorMoved { x, y } => {
+                            cursor_position =
+                                glutin::dpi::LogicalPosition::new(
+                                    f64::from(x),
+                                    f64::from(y),
+                                )
+                                .to_physical(viewport.scale_factor());
+                        }
+                        mouse::Event::CursorLeft => {
+                            cursor_position =
+                                glutin::dpi::PhysicalPosition::new(-1.0, -1.0);
+                        }
+                        _ => {}
+                    }
+
+                    let event = iced_native::Event::Mouse(mouse_event);
+
+                    recording.record(&event);
+                    state.queue_event(event.clone());
+                    runtime.broadcast(event);
+                }
             }
+
+            if *control_flow == ControlFlow::Wait {
+                if let Some(next_draw) = state.next_draw() {
+                    *control_flow = ControlFlow::WaitUntil(next_draw);
+                };
+            };
+        }
+        event::Event::LoopDestroyed => {
+            application::export_trace(&debug, None);
         }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            if is_exiting {
+                // Commands do not notify the event loop when they finish
+                // without producing a message, so we poll them
+                *control_flow = if runtime.has_pending_commands() {
+                    ControlFlow::WaitUntil(
+                        std::time::Instant::now()
+                            + std::time::Duration::from_millis(10),
+                    )
+                } else {
+                    ControlFlow::Exit
+                };
+
+                return;
+            }
+
+            *control_flow = if let Some(next_draw) = state.next_draw() {
+                ControlFlow::WaitUntil(next_draw)
+            } else {
+                ControlFlow::Wait
+            };
+
+            recording.wake_up(control_flow);
         }
     })
 }
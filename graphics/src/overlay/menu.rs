@@ -2,8 +2,8 @@
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::{
-    mouse, overlay, Color, Font, HorizontalAlignment, Point, Rectangle,
-    VerticalAlignment,
+    mouse, overlay, Color, Font, HorizontalAlignment, Padding, Point,
+    Rectangle, VerticalAlignment,
 };
 
 pub use iced_style::menu::Style;
@@ -44,7 +44,7 @@ where
         cursor_position: Point,
         options: &[T],
         hovered_option: Option<usize>,
-        padding: u16,
+        padding: Padding,
         text_size: u16,
         font: Font,
         style: &Style,
@@ -61,9 +61,10 @@ where
             let bounds = Rectangle {
                 x: bounds.x,
                 y: bounds.y
-                    + ((text_size as usize + padding as usize * 2) * i) as f32,
+                    + ((text_size as usize + padding.vertical() as usize) * i)
+                        as f32,
                 width: bounds.width,
-                height: f32::from(text_size + padding * 2),
+                height: f32::from(text_size + padding.vertical()),
             };
 
             if is_selected {
@@ -79,7 +80,7 @@ where
             primitives.push(Primitive::Text {
                 content: option.to_string(),
                 bounds: Rectangle {
-                    x: bounds.x + f32::from(padding),
+                    x: bounds.x + f32::from(padding.left),
                     y: bounds.center_y(),
                     width: f32::INFINITY,
                     ..bounds
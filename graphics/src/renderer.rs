@@ -9,6 +9,7 @@ use iced_native::{
 #[derive(Debug)]
 pub struct Renderer<B: Backend> {
     backend: B,
+    pub(crate) ime_area: Option<Rectangle>,
 }
 
 impl<B: Backend> Renderer<B> {
@@ -17,7 +18,10 @@ impl<B: Backend> Renderer<B> {
     /// [`Renderer`]: struct.Renderer.html
     /// [`Backend`]: backend/trait.Backend.html
     pub fn new(backend: B) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            ime_area: None,
+        }
     }
 
     /// Returns a reference to the [`Backend`] of the [`Renderer`].
@@ -84,6 +88,26 @@ where
             },
         )
     }
+
+    fn highlight(
+        &mut self,
+        base: (Primitive, mouse::Interaction),
+        bounds: Rectangle,
+    ) -> (Primitive, mouse::Interaction) {
+        let highlight = Primitive::Quad {
+            bounds,
+            background: Background::Color([0.0, 0.5, 1.0, 0.2].into()),
+            border_radius: 0,
+            border_width: 1,
+            border_color: [0.0, 0.5, 1.0, 0.8].into(),
+        };
+
+        self.overlay(base, (highlight, mouse::Interaction::default()), bounds)
+    }
+
+    fn take_ime_area(&mut self) -> Option<Rectangle> {
+        self.ime_area.take()
+    }
 }
 
 impl<B> layout::Debugger for Renderer<B>
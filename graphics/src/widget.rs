@@ -10,6 +10,7 @@
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod grid;
 pub mod image;
 pub mod pane_grid;
 pub mod pick_list;
@@ -18,13 +19,17 @@ pub mod radio;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod spinner;
+pub mod stack;
 pub mod svg;
 pub mod text_input;
+pub mod toggler;
 
 mod column;
 mod row;
 mod space;
 mod text;
+mod wrap;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -33,6 +38,8 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -47,7 +54,13 @@ pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use toggler::Toggler;
 
 pub use column::Column;
 pub use image::Image;
@@ -55,6 +68,7 @@ pub use row::Row;
 pub use space::Space;
 pub use svg::Svg;
 pub use text::Text;
+pub use wrap::Wrap;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -1,14 +1,23 @@
 use iced_native::keyboard;
 use iced_native::mouse;
+use iced_native::touch;
 
 /// A [`Canvas`] event.
 ///
 /// [`Canvas`]: struct.Event.html
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Event {
     /// A mouse event.
     Mouse(mouse::Event),
 
     /// A keyboard event.
     Keyboard(keyboard::Event),
+
+    /// A touch event.
+    ///
+    /// Single-finger interactions are also reported as mouse events. Use a
+    /// [`touch::gesture::Recognizer`] to detect pinch and pan gestures.
+    ///
+    /// [`touch::gesture::Recognizer`]: ../../../iced_native/touch/gesture/struct.Recognizer.html
+    Touch(touch::Event),
 }
@@ -0,0 +1,59 @@
+//! Lay out content in rows and columns.
+use crate::{Backend, Primitive, Renderer};
+use iced_native::grid;
+use iced_native::mouse;
+use iced_native::{Element, Layout, Point};
+
+/// A container that lays out its contents in rows and columns.
+///
+/// This is an alias of an `iced_native` grid with an
+/// `iced_graphics::Renderer`.
+pub type Grid<'a, Message, Backend> =
+    iced_native::Grid<'a, Message, Renderer<Backend>>;
+
+/// A cell of a [`Grid`], holding some content.
+///
+/// [`Grid`]: type.Grid.html
+pub type Cell<'a, Message, Backend> =
+    iced_native::grid::Cell<'a, Message, Renderer<Backend>>;
+
+impl<B> grid::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Self::Output {
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        (
+            Primitive::Group {
+                primitives: content
+                    .iter()
+                    .zip(layout.children())
+                    .map(|(child, layout)| {
+                        let (primitive, new_mouse_interaction) = child.draw(
+                            self,
+                            defaults,
+                            layout,
+                            cursor_position,
+                            draw_at,
+                        );
+
+                        if new_mouse_interaction > mouse_interaction {
+                            mouse_interaction = new_mouse_interaction;
+                        }
+
+                        primitive
+                    })
+                    .collect(),
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -7,7 +7,7 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::progress_bar;
-use iced_native::{Color, Rectangle};
+use iced_native::{Color, Rectangle, Vector};
 
 pub use iced_style::progress_bar::{Style, StyleSheet};
 
@@ -70,4 +70,51 @@ where
             mouse::Interaction::default(),
         )
     }
+
+    fn draw_indeterminate(
+        &self,
+        bounds: Rectangle,
+        cycle: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let segment_width = bounds.width * INDETERMINATE_SEGMENT;
+        let offset = (bounds.width + segment_width) * cycle - segment_width;
+
+        let background = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let bar = Primitive::Clip {
+            bounds,
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + offset,
+                    width: segment_width,
+                    ..bounds
+                },
+                background: style.bar,
+                border_radius: style.border_radius,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            }),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![background, bar],
+            },
+            mouse::Interaction::default(),
+        )
+    }
 }
+
+/// The width of the sliding segment of an indeterminate progress bar,
+/// relative to the width of the whole bar.
+const INDETERMINATE_SEGMENT: f32 = 0.3;
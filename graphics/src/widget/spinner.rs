@@ -0,0 +1,134 @@
+//! Show an endless animation while some work is being done.
+use crate::triangle::{Mesh2D, Vertex2D};
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::spinner;
+use iced_native::{Background, Rectangle, Size, Vector};
+
+use std::f32::consts::PI;
+
+pub use iced_style::progress_bar::{Style, StyleSheet};
+
+/// A circular spinner that indicates some work is being done.
+///
+/// This is an alias of an `iced_native` spinner with an
+/// `iced_graphics::Renderer`.
+pub type Spinner<Backend> = iced_native::Spinner<Renderer<Backend>>;
+
+/// The amount of segments used to tessellate a full ring.
+const SEGMENTS: usize = 64;
+
+impl<B> spinner::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 40;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cycle: f32,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let thickness = (radius / 4.0).max(1.0);
+        let center = [bounds.width / 2.0, bounds.height / 2.0];
+
+        // The arc rotates a full turn per cycle, while its sweep grows and
+        // shrinks to give it some momentum.
+        let sweep = PI / 4.0 + PI * (1.0 - (2.0 * PI * cycle).cos()) / 2.0;
+        let start = 2.0 * PI * cycle - PI / 2.0;
+
+        let mut mesh = Mesh2D {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        };
+
+        ring(
+            &mut mesh,
+            center,
+            radius,
+            thickness,
+            0.0,
+            2.0 * PI,
+            color(style.background),
+        );
+        ring(
+            &mut mesh,
+            center,
+            radius,
+            thickness,
+            start,
+            sweep,
+            color(style.bar),
+        );
+
+        (
+            Primitive::Translate {
+                translation: Vector::new(bounds.x, bounds.y),
+                content: Box::new(Primitive::Mesh2D {
+                    buffers: mesh,
+                    size: Size::new(bounds.width, bounds.height),
+                }),
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+fn color(background: Background) -> [f32; 4] {
+    match background {
+        Background::Color(color) => color.into_linear(),
+    }
+}
+
+/// Tessellates an arc of a ring and appends it to the given mesh.
+fn ring(
+    mesh: &mut Mesh2D,
+    center: [f32; 2],
+    radius: f32,
+    thickness: f32,
+    start: f32,
+    sweep: f32,
+    color: [f32; 4],
+) {
+    if color[3] <= 0.0 {
+        return;
+    }
+
+    let segments =
+        ((SEGMENTS as f32 * sweep / (2.0 * PI)).ceil() as usize).max(1);
+    let inner = radius - thickness;
+    let base = mesh.vertices.len() as u32;
+
+    for i in 0..=segments {
+        let angle = start + sweep * i as f32 / segments as f32;
+        let (sin, cos) = angle.sin_cos();
+
+        mesh.vertices.push(Vertex2D {
+            position: [center[0] + radius * cos, center[1] + radius * sin],
+            color,
+        });
+        mesh.vertices.push(Vertex2D {
+            position: [center[0] + inner * cos, center[1] + inner * sin],
+            color,
+        });
+    }
+
+    for i in 0..segments as u32 {
+        let outer = base + 2 * i;
+
+        mesh.indices.extend_from_slice(&[
+            outer,
+            outer + 1,
+            outer + 2,
+            outer + 1,
+            outer + 3,
+            outer + 2,
+        ]);
+    }
+}
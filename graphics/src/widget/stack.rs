@@ -0,0 +1,82 @@
+//! Display content on top of other content.
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::stack;
+use iced_native::{Element, Layout, Point, Rectangle, Vector};
+
+/// A container that displays its contents on top of each other.
+///
+/// This is an alias of an `iced_native` stack with an
+/// `iced_graphics::Renderer`.
+pub type Stack<'a, Message, Backend> =
+    iced_native::Stack<'a, Message, Renderer<Backend>>;
+
+/// A layer of a [`Stack`], holding some content.
+///
+/// [`Stack`]: type.Stack.html
+pub type Layer<'a, Message, Backend> =
+    iced_native::stack::Layer<'a, Message, Renderer<Backend>>;
+
+impl<B> stack::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Self::Output {
+        let topmost = stack::topmost(layout, cursor_position);
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        (
+            Primitive::Group {
+                primitives: content
+                    .iter()
+                    .zip(layout.children())
+                    .enumerate()
+                    .map(|(i, (child, layout))| {
+                        let (primitive, new_mouse_interaction) = child.draw(
+                            self,
+                            defaults,
+                            layout,
+                            stack::layer_cursor_position(
+                                i,
+                                topmost,
+                                cursor_position,
+                            ),
+                            draw_at,
+                        );
+
+                        if new_mouse_interaction > mouse_interaction {
+                            mouse_interaction = new_mouse_interaction;
+                        }
+
+                        if i == 0 {
+                            return primitive;
+                        }
+
+                        // Every layer above the first one is clipped to its
+                        // bounds, like an overlay, so the backend draws it
+                        // in a layer of its own
+                        let bounds = layout.bounds();
+
+                        Primitive::Clip {
+                            bounds: Rectangle {
+                                width: bounds.width + 0.5,
+                                height: bounds.height + 0.5,
+                                ..bounds
+                            },
+                            offset: Vector::new(0, 0),
+                            content: Box::new(primitive),
+                        }
+                    })
+                    .collect(),
+            },
+            mouse_interaction,
+        )
+    }
+}
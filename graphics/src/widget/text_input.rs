@@ -7,6 +7,7 @@
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::mouse;
+use iced_native::renderer::request_redraw;
 use iced_native::text_input::{self, cursor};
 use iced_native::{
     Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
@@ -100,6 +101,37 @@ where
             border_color: style.border_color,
         };
 
+        let cursor = state.cursor();
+
+        // The text being composed by an input method is shown at the cursor,
+        // before it is part of the value
+        let composed_value;
+        let (value, cursor_state, preedit) = match state
+            .preedit()
+            .filter(|_| state.is_focused())
+        {
+            Some(preedit) => {
+                let index = match cursor.state(value) {
+                    cursor::State::Index(index) => index,
+                    cursor::State::Selection { start, end } => start.min(end),
+                };
+
+                let preedit = text_input::Value::new(preedit);
+                let end = index + preedit.len();
+
+                let mut value = value.clone();
+                value.insert_many(index, preedit);
+                composed_value = value;
+
+                (
+                    &composed_value,
+                    cursor::State::Index(end),
+                    Some((index, end)),
+                )
+            }
+            None => (value, cursor.state(value), None),
+        };
+
         let text = value.to_string();
 
         let text_value = Primitive::Text {
@@ -124,103 +156,154 @@ where
             vertical_alignment: VerticalAlignment::Center,
         };
 
-        let cursor = state.cursor();
-        if let Some(next_draw) = cursor.next_draw() {
-            *draw_at = Some(next_draw);
+        request_redraw(draw_at, cursor.next_draw());
+
+        // The contents scroll to keep the focus position visible, whether the
+        // cursor is blinking or not
+        let offset = if state.is_focused() {
+            let focus_position = match cursor_state {
+                cursor::State::Index(i) => i,
+                cursor::State::Selection { end, .. } => end,
+            };
+
+            let (text_value_width, offset) = measure_cursor_and_scroll_offset(
+                self,
+                text_bounds,
+                value,
+                size,
+                focus_position,
+                font,
+            );
+
+            self.ime_area = Some(Rectangle {
+                x: text_bounds.x + text_value_width - offset,
+                y: text_bounds.y,
+                width: 1.0,
+                height: text_bounds.height,
+            });
+
+            offset
+        } else {
+            0.0
         };
 
-        let (contents_primitive, offset) =
-            if state.is_focused() && cursor.blink_visible() {
-                let (cursor_primitive, offset) = match cursor.state(value) {
-                    cursor::State::Index(position) => {
-                        let (text_value_width, offset) =
-                            measure_cursor_and_scroll_offset(
-                                self,
-                                text_bounds,
-                                value,
-                                size,
-                                position,
-                                font,
-                            );
-
-                        (
-                            Primitive::Quad {
-                                bounds: Rectangle {
-                                    x: text_bounds.x + text_value_width,
-                                    y: text_bounds.y,
-                                    width: 1.0,
-                                    height: text_bounds.height,
-                                },
-                                background: Background::Color(
-                                    style_sheet.value_color(),
-                                ),
-                                border_radius: 0,
-                                border_width: 0,
-                                border_color: Color::TRANSPARENT,
-                            },
-                            offset,
-                        )
-                    }
-                    cursor::State::Selection { start, end } => {
-                        let left = start.min(end);
-                        let right = end.max(start);
-
-                        let (left_position, left_offset) =
-                            measure_cursor_and_scroll_offset(
-                                self,
-                                text_bounds,
-                                value,
-                                size,
-                                left,
-                                font,
-                            );
-
-                        let (right_position, right_offset) =
-                            measure_cursor_and_scroll_offset(
-                                self,
-                                text_bounds,
-                                value,
-                                size,
-                                right,
-                                font,
-                            );
-
-                        let width = right_position - left_position;
-
-                        (
-                            Primitive::Quad {
-                                bounds: Rectangle {
-                                    x: text_bounds.x + left_position,
-                                    y: text_bounds.y,
-                                    width,
-                                    height: text_bounds.height,
-                                },
-                                background: Background::Color(
-                                    style_sheet.selection_color(),
-                                ),
-                                border_radius: 0,
-                                border_width: 0,
-                                border_color: Color::TRANSPARENT,
-                            },
-                            if end == right {
-                                right_offset
-                            } else {
-                                left_offset
-                            },
-                        )
+        let mut primitives = Vec::new();
+
+        if state.is_focused() && cursor.blink_visible() {
+            let cursor_primitive = match cursor_state {
+                cursor::State::Index(position) => {
+                    let (text_value_width, _) =
+                        measure_cursor_and_scroll_offset(
+                            self,
+                            text_bounds,
+                            value,
+                            size,
+                            position,
+                            font,
+                        );
+
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + text_value_width,
+                            y: text_bounds.y,
+                            width: 1.0,
+                            height: text_bounds.height,
+                        },
+                        background: Background::Color(
+                            style_sheet.value_color(),
+                        ),
+                        border_radius: 0,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
                     }
-                };
+                }
+                cursor::State::Selection { start, end } => {
+                    let left = start.min(end);
+                    let right = end.max(start);
 
-                (
-                    Primitive::Group {
-                        primitives: vec![cursor_primitive, text_value],
-                    },
-                    Vector::new(offset as u32, 0),
-                )
-            } else {
-                (text_value, Vector::new(0, 0))
+                    let (left_position, _) = measure_cursor_and_scroll_offset(
+                        self,
+                        text_bounds,
+                        value,
+                        size,
+                        left,
+                        font,
+                    );
+
+                    let (right_position, _) = measure_cursor_and_scroll_offset(
+                        self,
+                        text_bounds,
+                        value,
+                        size,
+                        right,
+                        font,
+                    );
+
+                    let width = right_position - left_position;
+
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + left_position,
+                            y: text_bounds.y,
+                            width,
+                            height: text_bounds.height,
+                        },
+                        background: Background::Color(
+                            style_sheet.selection_color(),
+                        ),
+                        border_radius: 0,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    }
+                }
             };
 
+            primitives.push(cursor_primitive);
+        }
+
+        primitives.push(text_value);
+
+        // The preedit is underlined along with the rest of the contents, so
+        // it scrolls and clips with them
+        if let Some((start, end)) = preedit {
+            let (start_position, _) = measure_cursor_and_scroll_offset(
+                self,
+                text_bounds,
+                value,
+                size,
+                start,
+                font,
+            );
+
+            let (end_position, _) = measure_cursor_and_scroll_offset(
+                self,
+                text_bounds,
+                value,
+                size,
+                end,
+                font,
+            );
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: text_bounds.x + start_position,
+                    y: text_bounds.center_y() + f32::from(size) / 2.0,
+                    width: end_position - start_position,
+                    height: 1.0,
+                },
+                background: Background::Color(style_sheet.value_color()),
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        let contents_primitive = if primitives.len() == 1 {
+            primitives.pop().unwrap()
+        } else {
+            Primitive::Group { primitives }
+        };
+
         let text_width = self.measure_value(
             if text.is_empty() { placeholder } else { &text },
             size,
@@ -230,7 +313,7 @@ where
         let contents = if text_width > text_bounds.width {
             Primitive::Clip {
                 bounds: text_bounds,
-                offset,
+                offset: Vector::new(offset as u32, 0),
                 content: Box::new(contents_primitive),
             }
         } else {
@@ -0,0 +1,79 @@
+//! Show toggle controls using togglers.
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::toggler;
+use iced_native::{Background, Color, Rectangle};
+
+pub use iced_native::toggler::{LabelPosition, State};
+pub use iced_style::toggler::{Style, StyleSheet};
+
+/// A toggler widget, an on/off switch with a label.
+///
+/// This is an alias of an `iced_native` toggler with an `iced_wgpu::Renderer`.
+pub type Toggler<'a, Message, Backend> =
+    iced_native::Toggler<'a, Message, Renderer<Backend>>;
+
+impl<B> toggler::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 20;
+    const DEFAULT_SPACING: u16 = 15;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        is_active: bool,
+        knob_position: f32,
+        is_mouse_over: bool,
+        (label, _): Self::Output,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = if is_mouse_over {
+            style_sheet.hovered(is_active)
+        } else {
+            style_sheet.active(is_active)
+        };
+
+        let border_radius = (bounds.height / 2.0) as u16;
+        let space = bounds.height * 0.1;
+
+        let switch = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.background),
+            border_radius,
+            border_width: 1,
+            border_color: style.background_border.unwrap_or(style.background),
+        };
+
+        let knob_size = bounds.height - 2.0 * space;
+        let knob_travel = bounds.width - knob_size - 2.0 * space;
+
+        let knob = Primitive::Quad {
+            bounds: Rectangle {
+                x: bounds.x + space + knob_travel * knob_position,
+                y: bounds.y + space,
+                width: knob_size,
+                height: knob_size,
+            },
+            background: Background::Color(style.foreground),
+            border_radius: (knob_size / 2.0) as u16,
+            border_width: 1,
+            border_color: style.foreground_border.unwrap_or(Color::TRANSPARENT),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![label, switch, knob],
+            },
+            if is_mouse_over {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
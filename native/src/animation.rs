@@ -0,0 +1,67 @@
+//! Animate values over time and schedule the redraws they need.
+//!
+//! An [`Animated`] value can be kept in the state of a widget or an
+//! application. Every time it is given a new target, it transitions smoothly
+//! from its current value using a [`Transition`], which can be either a
+//! [`Tween`] with an [`Easing`] curve or a physically-based [`Spring`].
+//! [`Keyframes`] can be used to describe more complex sequences.
+//!
+//! While an animation is running, its `next_draw` method returns the moment
+//! the screen needs to be redrawn, which can be reported by widgets through
+//! the `draw_at` argument of [`Widget::draw`] and by applications through
+//! [`Program::next_draw`].
+//!
+//! [`Animated`]: struct.Animated.html
+//! [`Transition`]: enum.Transition.html
+//! [`Tween`]: struct.Tween.html
+//! [`Easing`]: enum.Easing.html
+//! [`Spring`]: struct.Spring.html
+//! [`Keyframes`]: struct.Keyframes.html
+//! [`Widget::draw`]: ../widget/trait.Widget.html#tymethod.draw
+//! [`Program::next_draw`]: ../program/trait.Program.html#method.next_draw
+mod animated;
+mod easing;
+mod interpolate;
+mod keyframes;
+mod spring;
+mod transition;
+
+pub use animated::Animated;
+pub use easing::Easing;
+pub use interpolate::Interpolate;
+pub use keyframes::Keyframes;
+pub use spring::Spring;
+pub use transition::{Transition, Tween};
+
+use std::time::{Duration, Instant, SystemTime};
+
+/// The interval between redraws of a running animation.
+pub const FRAME: Duration = Duration::from_millis(16);
+
+/// Returns the progress, from `0.0` to `1.0`, of an endless cycle with the
+/// given period.
+///
+/// The progress is derived from the system clock, which allows stateless
+/// widgets to play looping animations. Widgets using it should keep
+/// requesting a redraw every [`FRAME`].
+///
+/// [`FRAME`]: constant.FRAME.html
+pub fn cycle(period: Duration) -> f32 {
+    let period = period.as_millis().max(1);
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+
+    (now % period) as f32 / period as f32
+}
+
+/// Returns the moment the next frame of an animation ending at `end` should be
+/// drawn, or `None` if the animation has already finished at `now`.
+fn next_frame(now: Instant, end: Instant) -> Option<Instant> {
+    if now < end {
+        Some(std::cmp::min(now + FRAME, end))
+    } else {
+        None
+    }
+}
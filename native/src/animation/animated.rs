@@ -0,0 +1,176 @@
+use crate::animation::{self, Interpolate, Transition};
+
+use std::time::Instant;
+
+/// A value that transitions smoothly every time it changes.
+///
+/// # Example
+///
+/// ```
+/// use iced_native::animation::{Animated, Easing, Tween};
+/// use std::time::Duration;
+///
+/// let mut opacity = Animated::new(
+///     0.0,
+///     Tween::new(Duration::from_millis(300)).easing(Easing::EaseOut),
+/// );
+///
+/// // Start fading in...
+/// opacity.set(1.0);
+///
+/// assert!(opacity.is_animating());
+/// assert!(opacity.next_draw().is_some());
+/// assert_eq!(opacity.target(), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Animated<T> {
+    from: T,
+    to: T,
+    transition: Transition,
+    started_at: Option<Instant>,
+}
+
+impl<T> Animated<T>
+where
+    T: Interpolate,
+{
+    /// Creates a new [`Animated`] value at rest with the given [`Transition`].
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Transition`]: enum.Transition.html
+    pub fn new(value: T, transition: impl Into<Transition>) -> Self {
+        Animated {
+            from: value,
+            to: value,
+            transition: transition.into(),
+            started_at: None,
+        }
+    }
+
+    /// Returns the current value of the [`Animated`] value.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn value(&self) -> T {
+        self.value_at(Instant::now())
+    }
+
+    /// Returns the value of the [`Animated`] value at the given moment.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn value_at(&self, now: Instant) -> T {
+        match self.started_at {
+            Some(started_at) => {
+                let elapsed = now.saturating_duration_since(started_at);
+
+                if elapsed >= self.transition.duration() {
+                    self.to
+                } else {
+                    self.from.interpolate(
+                        &self.to,
+                        self.transition.progress(elapsed),
+                    )
+                }
+            }
+            None => self.to,
+        }
+    }
+
+    /// Returns the target of the [`Animated`] value; the value it will have
+    /// once it stops animating.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn target(&self) -> T {
+        self.to
+    }
+
+    /// Returns the [`Transition`] of the [`Animated`] value.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Transition`]: enum.Transition.html
+    pub fn transition(&self) -> Transition {
+        self.transition
+    }
+
+    /// Sets the [`Transition`] used by the next changes of the [`Animated`]
+    /// value.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Transition`]: enum.Transition.html
+    pub fn set_transition(&mut self, transition: impl Into<Transition>) {
+        self.transition = transition.into();
+    }
+
+    /// Starts animating towards a new target from the current value.
+    ///
+    /// Nothing happens if the target does not change.
+    pub fn set(&mut self, target: T)
+    where
+        T: PartialEq,
+    {
+        self.set_at(target, Instant::now());
+    }
+
+    /// Starts animating towards a new target from the value at the given
+    /// moment.
+    ///
+    /// Nothing happens if the target does not change.
+    pub fn set_at(&mut self, target: T, now: Instant)
+    where
+        T: PartialEq,
+    {
+        if self.to != target {
+            self.from = self.value_at(now);
+            self.to = target;
+            self.started_at = Some(now);
+        }
+    }
+
+    /// Changes the value immediately, stopping any running animation.
+    pub fn snap(&mut self, value: T) {
+        self.from = value;
+        self.to = value;
+        self.started_at = None;
+    }
+
+    /// Returns whether the [`Animated`] value is currently changing.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn is_animating(&self) -> bool {
+        self.is_animating_at(Instant::now())
+    }
+
+    /// Returns whether the [`Animated`] value is changing at the given
+    /// moment.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn is_animating_at(&self, now: Instant) -> bool {
+        self.next_draw_at(now).is_some()
+    }
+
+    /// Returns the moment the [`Animated`] value needs to be drawn again,
+    /// or `None` if it is at rest.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn next_draw(&self) -> Option<Instant> {
+        self.next_draw_at(Instant::now())
+    }
+
+    /// Returns the moment after `now` the [`Animated`] value needs to be
+    /// drawn again, or `None` if it is at rest.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn next_draw_at(&self, now: Instant) -> Option<Instant> {
+        let started_at = self.started_at?;
+
+        animation::next_frame(now, started_at + self.transition.duration())
+    }
+}
+
+impl<T> Default for Animated<T>
+where
+    T: Interpolate + Default,
+{
+    fn default() -> Self {
+        Animated::new(T::default(), Transition::default())
+    }
+}
@@ -0,0 +1,92 @@
+/// A curve describing the rate of change of an animation over time.
+#[derive(Debug, Clone, Copy)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Starts slow and accelerates (quadratic).
+    EaseInQuad,
+
+    /// Starts fast and decelerates (quadratic).
+    EaseOutQuad,
+
+    /// Accelerates until halfway, then decelerates (quadratic).
+    EaseInOutQuad,
+
+    /// Starts slow and accelerates (cubic).
+    EaseIn,
+
+    /// Starts fast and decelerates (cubic).
+    EaseOut,
+
+    /// Accelerates until halfway, then decelerates (cubic).
+    EaseInOut,
+
+    /// Pulls back slightly before moving towards the target.
+    EaseInBack,
+
+    /// Overshoots the target slightly before settling.
+    EaseOutBack,
+
+    /// A custom curve mapping the elapsed fraction of time, from `0.0` to
+    /// `1.0`, to the progress of the animation.
+    ///
+    /// The curve should map `0.0` to `0.0` and `1.0` to `1.0`.
+    Custom(fn(f32) -> f32),
+}
+
+// Deriving `Default` for enums needs a newer compiler than we support
+#[allow(clippy::derivable_impls)]
+impl Default for Easing {
+    fn default() -> Self {
+        Easing::EaseInOut
+    }
+}
+
+impl Easing {
+    /// Returns the progress of an animation after the given fraction of its
+    /// duration has elapsed.
+    ///
+    /// The fraction is clamped to the `0.0` - `1.0` range. The progress is
+    /// usually in the same range, but some curves may overshoot it.
+    pub fn ease(self, t: f32) -> f32 {
+        const BACK: f32 = 1.70158;
+
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInQuad => t * t,
+            Easing::EaseOutQuad => t * (2.0 - t),
+            Easing::EaseInOutQuad => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => {
+                let t = t - 1.0;
+
+                t * t * t + 1.0
+            }
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let t = 2.0 * t - 2.0;
+
+                    0.5 * t * t * t + 1.0
+                }
+            }
+            Easing::EaseInBack => t * t * ((BACK + 1.0) * t - BACK),
+            Easing::EaseOutBack => {
+                let t = t - 1.0;
+
+                t * t * ((BACK + 1.0) * t + BACK) + 1.0
+            }
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
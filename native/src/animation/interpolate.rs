@@ -0,0 +1,51 @@
+use crate::{Color, Point, Vector};
+
+/// A value that can be interpolated, and therefore animated.
+pub trait Interpolate: Copy {
+    /// Returns the value found at `t` between `self` (`0.0`) and `other`
+    /// (`1.0`).
+    ///
+    /// `t` may fall slightly outside of the `0.0` - `1.0` range when an
+    /// [`Easing`] curve or a [`Spring`] overshoots.
+    ///
+    /// [`Easing`]: enum.Easing.html
+    /// [`Spring`]: struct.Spring.html
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let channel = |a: f32, b: f32| a.interpolate(&b, t).clamp(0.0, 1.0);
+
+        Color {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+            a: channel(self.a, other.a),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use crate::animation::{self, Easing, Interpolate};
+
+use std::time::{Duration, Instant};
+
+/// A sequence of values, each reached after a given duration, that can be
+/// played once or in a loop.
+///
+/// # Example
+///
+/// ```
+/// use iced_native::animation::{Easing, Keyframes};
+/// use std::time::Duration;
+///
+/// let mut pulse = Keyframes::new(1.0)
+///     .then(Duration::from_millis(400), 1.2, Easing::EaseOut)
+///     .then(Duration::from_millis(400), 1.0, Easing::EaseIn)
+///     .looping(true);
+///
+/// pulse.start();
+///
+/// assert!(pulse.is_running());
+/// assert_eq!(pulse.duration(), Duration::from_millis(800));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Keyframes<T> {
+    initial: T,
+    frames: Vec<Frame<T>>,
+    is_looping: bool,
+    started_at: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Frame<T> {
+    duration: Duration,
+    value: T,
+    easing: Easing,
+}
+
+impl<T> Keyframes<T>
+where
+    T: Interpolate,
+{
+    /// Creates new [`Keyframes`] starting at the given value.
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn new(initial: T) -> Self {
+        Keyframes {
+            initial,
+            frames: Vec::new(),
+            is_looping: false,
+            started_at: None,
+        }
+    }
+
+    /// Adds a keyframe reached after the given duration since the previous
+    /// one, following the provided [`Easing`] curve.
+    ///
+    /// [`Easing`]: enum.Easing.html
+    pub fn then(
+        mut self,
+        duration: Duration,
+        value: T,
+        easing: Easing,
+    ) -> Self {
+        self.frames.push(Frame {
+            duration,
+            value,
+            easing,
+        });
+        self
+    }
+
+    /// Sets whether the [`Keyframes`] start over once they finish.
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+
+    /// Returns the total duration of the [`Keyframes`].
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.duration).sum()
+    }
+
+    /// Starts playing the [`Keyframes`] from the beginning.
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn start(&mut self) {
+        self.start_at(Instant::now());
+    }
+
+    /// Starts playing the [`Keyframes`] from the beginning at the given
+    /// moment.
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn start_at(&mut self, now: Instant) {
+        self.started_at = Some(now);
+    }
+
+    /// Stops playing the [`Keyframes`], resetting them to their initial
+    /// value.
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn stop(&mut self) {
+        self.started_at = None;
+    }
+
+    /// Returns the current value of the [`Keyframes`].
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn value(&self) -> T {
+        self.value_at(Instant::now())
+    }
+
+    /// Returns the value of the [`Keyframes`] at the given moment.
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn value_at(&self, now: Instant) -> T {
+        let started_at = match self.started_at {
+            Some(started_at) => started_at,
+            None => return self.initial,
+        };
+
+        let total = self.duration();
+        let mut elapsed = now.saturating_duration_since(started_at);
+
+        if elapsed >= total {
+            if self.is_looping && total > Duration::from_secs(0) {
+                elapsed = Duration::from_secs_f64(
+                    elapsed.as_secs_f64() % total.as_secs_f64(),
+                );
+            } else {
+                return self.frames.last().map_or(self.initial, |f| f.value);
+            }
+        }
+
+        let mut from = self.initial;
+
+        for frame in &self.frames {
+            if elapsed < frame.duration {
+                let t = elapsed.as_secs_f32() / frame.duration.as_secs_f32();
+
+                return from.interpolate(&frame.value, frame.easing.ease(t));
+            }
+
+            elapsed -= frame.duration;
+            from = frame.value;
+        }
+
+        from
+    }
+
+    /// Returns whether the [`Keyframes`] are currently playing.
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn is_running(&self) -> bool {
+        self.next_draw().is_some()
+    }
+
+    /// Returns the moment the [`Keyframes`] need to be drawn again, or
+    /// `None` if they are not playing.
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn next_draw(&self) -> Option<Instant> {
+        self.next_draw_at(Instant::now())
+    }
+
+    /// Returns the moment after `now` the [`Keyframes`] need to be drawn
+    /// again, or `None` if they are not playing.
+    ///
+    /// [`Keyframes`]: struct.Keyframes.html
+    pub fn next_draw_at(&self, now: Instant) -> Option<Instant> {
+        let started_at = self.started_at?;
+
+        if self.is_looping && !self.frames.is_empty() {
+            Some(now + animation::FRAME)
+        } else {
+            animation::next_frame(now, started_at + self.duration())
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+/// The distance from the target, relative to the total distance travelled,
+/// under which a [`Spring`] is considered to be at rest.
+///
+/// [`Spring`]: struct.Spring.html
+const REST_THRESHOLD: f32 = 0.001;
+
+/// A physically-based transition modeled as a damped spring with unit mass.
+///
+/// Unlike a [`Tween`], a [`Spring`] has no fixed duration: it settles when its
+/// oscillation becomes imperceptible. An underdamped [`Spring`] overshoots its
+/// target before settling.
+///
+/// [`Tween`]: struct.Tween.html
+/// [`Spring`]: struct.Spring.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    stiffness: f32,
+    damping: f32,
+}
+
+impl Spring {
+    /// A slow [`Spring`] without noticeable overshoot.
+    ///
+    /// [`Spring`]: struct.Spring.html
+    pub const GENTLE: Spring = Spring {
+        stiffness: 120.0,
+        damping: 14.0,
+    };
+
+    /// A bouncy [`Spring`].
+    ///
+    /// [`Spring`]: struct.Spring.html
+    pub const WOBBLY: Spring = Spring {
+        stiffness: 180.0,
+        damping: 12.0,
+    };
+
+    /// A fast [`Spring`].
+    ///
+    /// [`Spring`]: struct.Spring.html
+    pub const STIFF: Spring = Spring {
+        stiffness: 210.0,
+        damping: 20.0,
+    };
+
+    /// Creates a new [`Spring`] with the given stiffness and damping.
+    ///
+    /// Both values must be positive.
+    ///
+    /// [`Spring`]: struct.Spring.html
+    pub fn new(stiffness: f32, damping: f32) -> Spring {
+        debug_assert!(stiffness > 0.0, "Stiffness must be positive");
+        debug_assert!(damping > 0.0, "Damping must be positive");
+
+        Spring { stiffness, damping }
+    }
+
+    /// Returns the stiffness of the [`Spring`].
+    ///
+    /// [`Spring`]: struct.Spring.html
+    pub fn stiffness(&self) -> f32 {
+        self.stiffness
+    }
+
+    /// Returns the damping of the [`Spring`].
+    ///
+    /// [`Spring`]: struct.Spring.html
+    pub fn damping(&self) -> f32 {
+        self.damping
+    }
+
+    /// Returns the progress of the [`Spring`] towards its target after the
+    /// given time has elapsed, starting at rest at `0.0`.
+    ///
+    /// [`Spring`]: struct.Spring.html
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        if elapsed >= self.duration() {
+            return 1.0;
+        }
+
+        1.0 + self.displacement(elapsed.as_secs_f32())
+    }
+
+    /// Returns the time the [`Spring`] needs to settle.
+    ///
+    /// [`Spring`]: struct.Spring.html
+    pub fn duration(&self) -> Duration {
+        let omega = self.stiffness.sqrt();
+        let zeta = self.damping / (2.0 * omega);
+
+        // The displacement is bounded by `amplitude * e^(-decay * t)`
+        let (amplitude, decay) = if zeta < 1.0 {
+            (1.0 + zeta / (1.0 - zeta * zeta).sqrt(), zeta * omega)
+        } else if zeta > 1.0 {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let slow = omega * (zeta - root);
+            let fast = omega * (zeta + root);
+
+            ((fast + slow) / (fast - slow), slow)
+        } else {
+            // `(1 + omega * t) * e^(-omega * t)` is bounded by
+            // `e^(-omega * t / 2) * 2 / e^(1/2)`
+            (2.0 / 0.5f32.exp(), omega / 2.0)
+        };
+
+        Duration::from_secs_f32(
+            ((amplitude / REST_THRESHOLD).ln() / decay).max(0.0),
+        )
+    }
+
+    /// Returns the displacement from the target at time `t`, in seconds,
+    /// having started at rest at `-1.0`.
+    fn displacement(&self, t: f32) -> f32 {
+        let omega = self.stiffness.sqrt();
+        let zeta = self.damping / (2.0 * omega);
+
+        if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let envelope = (-zeta * omega * t).exp();
+
+            -envelope
+                * ((omega_d * t).cos()
+                    + zeta * omega / omega_d * (omega_d * t).sin())
+        } else if zeta > 1.0 {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+
+            let c2 = r1 / (r2 - r1);
+            let c1 = -1.0 - c2;
+
+            c1 * (r1 * t).exp() + c2 * (r2 * t).exp()
+        } else {
+            -(1.0 + omega * t) * (-omega * t).exp()
+        }
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Spring::new(170.0, 26.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_settles(spring: Spring) {
+        let duration = spring.duration();
+
+        assert!(spring.progress(Duration::from_secs(0)).abs() < 1e-6);
+        assert_eq!(spring.progress(duration), 1.0);
+
+        let before_end = duration.as_secs_f32() * 0.999;
+        let displacement = spring.displacement(before_end);
+
+        assert!(displacement.abs() < REST_THRESHOLD * 2.0);
+    }
+
+    #[test]
+    fn underdamped_spring_overshoots_and_settles() {
+        let spring = Spring::WOBBLY;
+
+        let overshoots = (1..100)
+            .map(|i| spring.progress(Duration::from_millis(i * 10)))
+            .any(|progress| progress > 1.0);
+
+        assert!(overshoots);
+        assert_settles(spring);
+    }
+
+    #[test]
+    fn critically_damped_spring_settles() {
+        assert_settles(Spring::new(100.0, 20.0));
+    }
+
+    #[test]
+    fn overdamped_spring_does_not_overshoot() {
+        let spring = Spring::new(100.0, 40.0);
+
+        let overshoots = (1..200)
+            .map(|i| spring.progress(Duration::from_millis(i * 10)))
+            .any(|progress| progress > 1.0);
+
+        assert!(!overshoots);
+        assert_settles(spring);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::animation::{Easing, Spring};
+
+use std::time::Duration;
+
+/// The way an animated value moves from one value to another.
+#[derive(Debug, Clone, Copy)]
+pub enum Transition {
+    /// A transition with a fixed duration following an [`Easing`] curve.
+    ///
+    /// [`Easing`]: enum.Easing.html
+    Tween(Tween),
+
+    /// A physically-based transition.
+    Spring(Spring),
+}
+
+impl Transition {
+    /// Returns the total duration of the [`Transition`].
+    ///
+    /// [`Transition`]: enum.Transition.html
+    pub fn duration(&self) -> Duration {
+        match self {
+            Transition::Tween(tween) => tween.duration,
+            Transition::Spring(spring) => spring.duration(),
+        }
+    }
+
+    /// Returns the progress of the [`Transition`] after the given time has
+    /// elapsed, from `0.0` (start) to `1.0` (end).
+    ///
+    /// The progress may overshoot `1.0` temporarily, depending on the
+    /// [`Easing`] curve or the [`Spring`] used.
+    ///
+    /// [`Transition`]: enum.Transition.html
+    /// [`Easing`]: enum.Easing.html
+    /// [`Spring`]: struct.Spring.html
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        match self {
+            Transition::Tween(tween) => tween.progress(elapsed),
+            Transition::Spring(spring) => spring.progress(elapsed),
+        }
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Transition::Tween(Tween::default())
+    }
+}
+
+impl From<Tween> for Transition {
+    fn from(tween: Tween) -> Transition {
+        Transition::Tween(tween)
+    }
+}
+
+impl From<Spring> for Transition {
+    fn from(spring: Spring) -> Transition {
+        Transition::Spring(spring)
+    }
+}
+
+impl From<Duration> for Transition {
+    fn from(duration: Duration) -> Transition {
+        Transition::Tween(Tween::new(duration))
+    }
+}
+
+/// A transition with a fixed duration following an [`Easing`] curve.
+///
+/// [`Easing`]: enum.Easing.html
+#[derive(Debug, Clone, Copy)]
+pub struct Tween {
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Tween {
+    /// Creates a new [`Tween`] with the given duration and the default
+    /// [`Easing`] curve.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    /// [`Easing`]: enum.Easing.html
+    pub fn new(duration: Duration) -> Tween {
+        Tween {
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Easing`] curve of the [`Tween`].
+    ///
+    /// [`Tween`]: struct.Tween.html
+    /// [`Easing`]: enum.Easing.html
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the duration of the [`Tween`].
+    ///
+    /// [`Tween`]: struct.Tween.html
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the progress of the [`Tween`] after the given time has
+    /// elapsed.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        if elapsed >= self.duration {
+            1.0
+        } else {
+            self.easing
+                .ease(elapsed.as_secs_f32() / self.duration.as_secs_f32())
+        }
+    }
+}
+
+impl Default for Tween {
+    fn default() -> Self {
+        Tween::new(Duration::from_millis(200))
+    }
+}
@@ -1,11 +1,30 @@
 #![allow(missing_docs)]
-use std::{collections::VecDeque, time};
+use crate::{layout, Layout, Point, Rectangle, Size};
+
+use std::io::{self, Write};
+use std::path::Path;
+use std::{collections::VecDeque, fs, time};
+
+/// The environment variable that sets the maximum amount of spans kept in
+/// the performance trace.
+const TRACE_CAPACITY_VARIABLE: &str = "ICED_TRACE_CAPACITY";
+
+/// The maximum amount of spans kept in the performance trace by default.
+///
+/// A frame usually records a handful of spans, so it keeps the last couple
+/// thousand frames.
+const DEFAULT_TRACE_CAPACITY: usize = 10_000;
 
 /// A bunch of time measurements for debugging purposes.
 #[derive(Debug)]
 pub struct Debug {
     is_enabled: bool,
 
+    epoch: time::Instant,
+    frame: u64,
+    trace: VecDeque<Span>,
+    trace_capacity: usize,
+
     startup_start: time::Instant,
     startup_duration: time::Duration,
 
@@ -29,6 +48,10 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+
+    is_inspecting: bool,
+    is_layout_dump_requested: bool,
+    inspected: Option<(Rectangle, layout::Info)>,
 }
 
 impl Debug {
@@ -40,6 +63,15 @@ impl Debug {
 
         Self {
             is_enabled: false,
+
+            epoch: now,
+            frame: 0,
+            trace: VecDeque::new(),
+            trace_capacity: std::env::var(TRACE_CAPACITY_VARIABLE)
+                .ok()
+                .and_then(|capacity| capacity.parse().ok())
+                .unwrap_or(DEFAULT_TRACE_CAPACITY),
+
             startup_start: now,
             startup_duration: time::Duration::from_secs(0),
 
@@ -63,6 +95,10 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+
+            is_inspecting: false,
+            is_layout_dump_requested: false,
+            inspected: None,
         }
     }
 
@@ -70,12 +106,54 @@ impl Debug {
         self.is_enabled = !self.is_enabled;
     }
 
+    /// Toggles the layout inspector, which highlights the widget under the
+    /// cursor and shows its layout details in the overlay.
+    pub fn toggle_inspector(&mut self) {
+        self.is_inspecting = !self.is_inspecting;
+        self.inspected = None;
+    }
+
+    /// Requests the layout tree to be printed to the standard error the
+    /// next time a user interface is inspected.
+    pub fn request_layout_dump(&mut self) {
+        self.is_layout_dump_requested = true;
+    }
+
+    /// Inspects the [`Layout`] of a user interface with the given cursor
+    /// position.
+    ///
+    /// [`Layout`]: ../layout/struct.Layout.html
+    pub fn inspect(&mut self, layout: Layout<'_>, cursor_position: Point) {
+        if self.is_layout_dump_requested {
+            self.is_layout_dump_requested = false;
+
+            let mut dump = String::new();
+            dump_layout(layout, 0, &mut dump);
+
+            eprint!("{}", dump);
+        }
+
+        if self.is_inspecting {
+            self.inspected = None;
+
+            find_inspected(layout, cursor_position, &mut self.inspected);
+        }
+    }
+
+    /// Returns the bounds of the widget highlighted by the layout
+    /// inspector, if any.
+    pub fn inspected_bounds(&self) -> Option<Rectangle> {
+        self.inspected.map(|(bounds, _)| bounds)
+    }
+
     pub fn startup_started(&mut self) {
         self.startup_start = time::Instant::now();
     }
 
     pub fn startup_finished(&mut self) {
         self.startup_duration = time::Instant::now() - self.startup_start;
+
+        self.record(Stage::Startup, self.startup_start, self.startup_duration);
     }
 
     pub fn update_started(&mut self) {
@@ -83,8 +161,10 @@ impl Debug {
     }
 
     pub fn update_finished(&mut self) {
-        self.update_durations
-            .push(time::Instant::now() - self.update_start);
+        let duration = time::Instant::now() - self.update_start;
+
+        self.update_durations.push(duration);
+        self.record(Stage::Update, self.update_start, duration);
     }
 
     pub fn view_started(&mut self) {
@@ -92,8 +172,10 @@ impl Debug {
     }
 
     pub fn view_finished(&mut self) {
-        self.view_durations
-            .push(time::Instant::now() - self.view_start);
+        let duration = time::Instant::now() - self.view_start;
+
+        self.view_durations.push(duration);
+        self.record(Stage::View, self.view_start, duration);
     }
 
     pub fn layout_started(&mut self) {
@@ -101,8 +183,10 @@ impl Debug {
     }
 
     pub fn layout_finished(&mut self) {
-        self.layout_durations
-            .push(time::Instant::now() - self.layout_start);
+        let duration = time::Instant::now() - self.layout_start;
+
+        self.layout_durations.push(duration);
+        self.record(Stage::Layout, self.layout_start, duration);
     }
 
     pub fn event_processing_started(&mut self) {
@@ -110,8 +194,10 @@ impl Debug {
     }
 
     pub fn event_processing_finished(&mut self) {
-        self.event_durations
-            .push(time::Instant::now() - self.event_start);
+        let duration = time::Instant::now() - self.event_start;
+
+        self.event_durations.push(duration);
+        self.record(Stage::EventProcessing, self.event_start, duration);
     }
 
     pub fn draw_started(&mut self) {
@@ -119,8 +205,10 @@ impl Debug {
     }
 
     pub fn draw_finished(&mut self) {
-        self.draw_durations
-            .push(time::Instant::now() - self.draw_start);
+        let duration = time::Instant::now() - self.draw_start;
+
+        self.draw_durations.push(duration);
+        self.record(Stage::Draw, self.draw_start, duration);
     }
 
     pub fn render_started(&mut self) {
@@ -128,8 +216,13 @@ impl Debug {
     }
 
     pub fn render_finished(&mut self) {
-        self.render_durations
-            .push(time::Instant::now() - self.render_start);
+        let duration = time::Instant::now() - self.render_start;
+
+        self.render_durations.push(duration);
+        self.record(Stage::Render, self.render_start, duration);
+
+        // Every frame ends once it is rendered
+        self.frame += 1;
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
@@ -142,13 +235,124 @@ impl Debug {
         self.message_count += 1;
     }
 
-    pub fn overlay(&self) -> Vec<String> {
-        if !self.is_enabled {
-            return Vec::new();
+    /// Exports the performance trace recorded so far to the file at the
+    /// given path.
+    ///
+    /// The trace is written as CSV if the file has a `csv` extension.
+    /// Otherwise, it is written in the [trace event format] of Chrome, which
+    /// can be opened with `chrome://tracing` or [Perfetto].
+    ///
+    /// Only the last 10 000 spans are kept by default. The
+    /// `ICED_TRACE_CAPACITY` environment variable sets a different amount.
+    ///
+    /// [trace event format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+    /// [Perfetto]: https://ui.perfetto.dev
+    pub fn export_trace(&self, path: &Path) -> io::Result<()> {
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+
+        let is_csv = path
+            .extension()
+            .map(|extension| extension.eq_ignore_ascii_case("csv"))
+            .unwrap_or(false);
+
+        self.write_trace(&mut file, is_csv)?;
+
+        file.flush()
+    }
+
+    fn write_trace(
+        &self,
+        mut file: impl Write,
+        is_csv: bool,
+    ) -> io::Result<()> {
+        if is_csv {
+            writeln!(file, "frame,stage,start_us,duration_us")?;
+
+            for span in &self.trace {
+                writeln!(
+                    file,
+                    "{},{},{},{}",
+                    span.frame,
+                    span.stage.name(),
+                    span.start.as_micros(),
+                    span.duration.as_micros()
+                )?;
+            }
+        } else {
+            writeln!(file, "{{\"traceEvents\":[")?;
+
+            for (i, span) in self.trace.iter().enumerate() {
+                writeln!(
+                    file,
+                    "{{\"name\":\"{}\",\"cat\":\"iced\",\"ph\":\"X\",\
+                     \"ts\":{},\"dur\":{},\"pid\":1,\"tid\":1,\
+                     \"args\":{{\"frame\":{}}}}}{}",
+                    span.stage.name(),
+                    span.start.as_micros(),
+                    span.duration.as_micros(),
+                    span.frame,
+                    if i + 1 < self.trace.len() { "," } else { "" }
+                )?;
+            }
+
+            writeln!(file, "]}}")?;
         }
 
+        Ok(())
+    }
+
+    fn record(
+        &mut self,
+        stage: Stage,
+        start: time::Instant,
+        duration: time::Duration,
+    ) {
+        if self.trace_capacity == 0 {
+            return;
+        }
+
+        while self.trace.len() >= self.trace_capacity {
+            let _ = self.trace.pop_front();
+        }
+
+        self.trace.push_back(Span {
+            frame: self.frame,
+            stage,
+            start: start.saturating_duration_since(self.epoch),
+            duration,
+        });
+    }
+
+    pub fn overlay(&self) -> Vec<String> {
         let mut lines = Vec::new();
 
+        if self.is_inspecting {
+            lines.push(String::from("Inspector:"));
+
+            match self.inspected {
+                Some((bounds, info)) => {
+                    lines.push(format!("    {}", short_name(info.widget)));
+                    lines.push(format!("    Bounds: {}", rectangle(bounds)));
+                    lines.push(format!(
+                        "    Width: {:?}, Height: {:?}",
+                        info.width, info.height
+                    ));
+                    lines.push(format!(
+                        "    Limits: {} to {}",
+                        size(info.limits.min()),
+                        size(info.limits.max())
+                    ));
+                }
+                None => {
+                    lines.push(String::from("    Nothing under the cursor"));
+                }
+            }
+        }
+
+        if !self.is_enabled {
+            return lines;
+        }
+
         fn key_value<T: std::fmt::Debug>(key: &str, value: T) -> String {
             format!("{} {:?}", key, value)
         }
@@ -186,6 +390,101 @@ impl Debug {
     }
 }
 
+fn find_inspected(
+    layout: Layout<'_>,
+    cursor_position: Point,
+    inspected: &mut Option<(Rectangle, layout::Info)>,
+) {
+    let bounds = layout.bounds();
+
+    if !bounds.contains(cursor_position) {
+        return;
+    }
+
+    // Nodes produced by the internals of a widget have no info
+    if let Some(info) = layout.info() {
+        *inspected = Some((bounds, *info));
+    }
+
+    for child in layout.children() {
+        find_inspected(child, cursor_position, inspected);
+    }
+}
+
+fn dump_layout(layout: Layout<'_>, depth: usize, dump: &mut String) {
+    use std::fmt::Write;
+
+    let indent = "  ".repeat(depth);
+    let bounds = rectangle(layout.bounds());
+
+    let _ = match layout.info() {
+        Some(info) => writeln!(
+            dump,
+            "{}{} {} width: {:?}, height: {:?}, limits: {} to {}",
+            indent,
+            short_name(info.widget),
+            bounds,
+            info.width,
+            info.height,
+            size(info.limits.min()),
+            size(info.limits.max()),
+        ),
+        None => writeln!(dump, "{}- {}", indent, bounds),
+    };
+
+    for child in layout.children() {
+        dump_layout(child, depth + 1, dump);
+    }
+}
+
+fn short_name(type_name: &str) -> &str {
+    let path = type_name.split('<').next().unwrap_or(type_name);
+
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+fn rectangle(bounds: Rectangle) -> String {
+    format!("({}, {}) {}", bounds.x, bounds.y, size(bounds.size()))
+}
+
+fn size(size: Size) -> String {
+    format!("{}x{}", size.width, size.height)
+}
+
+/// A measured stage of a frame in the performance trace.
+#[derive(Debug, Clone, Copy)]
+struct Span {
+    frame: u64,
+    stage: Stage,
+    start: time::Duration,
+    duration: time::Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Stage {
+    Startup,
+    Update,
+    View,
+    Layout,
+    EventProcessing,
+    Draw,
+    Render,
+}
+
+impl Stage {
+    fn name(self) -> &'static str {
+        match self {
+            Stage::Startup => "Startup",
+            Stage::Update => "Update",
+            Stage::View => "View",
+            Stage::Layout => "Layout",
+            Stage::EventProcessing => "Event processing",
+            Stage::Draw => "Draw",
+            Stage::Render => "Render",
+        }
+    }
+}
+
 #[derive(Debug)]
 struct TimeBuffer {
     head: usize,
@@ -218,3 +517,79 @@ impl TimeBuffer {
         sum / self.size.max(1) as u32
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace() -> Debug {
+        let mut debug = Debug::new();
+
+        debug.trace = vec![
+            Span {
+                frame: 0,
+                stage: Stage::Startup,
+                start: time::Duration::from_micros(0),
+                duration: time::Duration::from_micros(1500),
+            },
+            Span {
+                frame: 1,
+                stage: Stage::EventProcessing,
+                start: time::Duration::from_micros(2000),
+                duration: time::Duration::from_micros(250),
+            },
+        ]
+        .into();
+
+        debug
+    }
+
+    fn write(debug: &Debug, is_csv: bool) -> String {
+        let mut output = Vec::new();
+
+        debug.write_trace(&mut output, is_csv).unwrap();
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn trace_is_exported_as_csv() {
+        assert_eq!(
+            write(&trace(), true),
+            "frame,stage,start_us,duration_us\n\
+             0,Startup,0,1500\n\
+             1,Event processing,2000,250\n"
+        );
+    }
+
+    #[test]
+    fn trace_is_exported_as_chrome_json() {
+        assert_eq!(
+            write(&trace(), false),
+            "{\"traceEvents\":[\n\
+             {\"name\":\"Startup\",\"cat\":\"iced\",\"ph\":\"X\",\
+             \"ts\":0,\"dur\":1500,\"pid\":1,\"tid\":1,\
+             \"args\":{\"frame\":0}},\n\
+             {\"name\":\"Event processing\",\"cat\":\"iced\",\"ph\":\"X\",\
+             \"ts\":2000,\"dur\":250,\"pid\":1,\"tid\":1,\
+             \"args\":{\"frame\":1}}\n\
+             ]}\n"
+        );
+    }
+
+    #[test]
+    fn trace_keeps_the_latest_spans() {
+        let mut debug = Debug::new();
+        debug.trace_capacity = 2;
+
+        for _ in 0..3 {
+            debug.render_started();
+            debug.render_finished();
+        }
+
+        let frames: Vec<_> =
+            debug.trace.iter().map(|span| span.frame).collect();
+
+        assert_eq!(frames, vec![1, 2]);
+    }
+}
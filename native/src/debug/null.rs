@@ -1,4 +1,9 @@
 #![allow(missing_docs)]
+use crate::{Layout, Point, Rectangle};
+
+use std::io;
+use std::path::Path;
+
 #[derive(Debug)]
 pub struct Debug;
 
@@ -7,6 +12,16 @@ impl Debug {
         Self
     }
 
+    pub fn toggle_inspector(&mut self) {}
+
+    pub fn request_layout_dump(&mut self) {}
+
+    pub fn inspect(&mut self, _layout: Layout<'_>, _cursor_position: Point) {}
+
+    pub fn inspected_bounds(&self) -> Option<Rectangle> {
+        None
+    }
+
     pub fn startup_started(&mut self) {}
 
     pub fn startup_finished(&mut self) {}
@@ -41,6 +56,13 @@ impl Debug {
     ) {
     }
 
+    pub fn export_trace(&self, _path: &Path) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "performance traces need the `debug` feature",
+        ))
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         Vec::new()
     }
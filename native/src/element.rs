@@ -17,6 +17,8 @@ use crate::{
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Renderer> {
     pub(crate) widget: Box<dyn Widget<Message, Renderer> + 'a>,
+    #[cfg(feature = "debug")]
+    name: &'static str,
 }
 
 impl<'a, Message, Renderer> Element<'a, Message, Renderer>
@@ -27,11 +29,14 @@ where
     ///
     /// [`Element`]: struct.Element.html
     /// [`Widget`]: widget/trait.Widget.html
-    pub fn new(
-        widget: impl Widget<Message, Renderer> + 'a,
-    ) -> Element<'a, Message, Renderer> {
+    pub fn new<W>(widget: W) -> Element<'a, Message, Renderer>
+    where
+        W: Widget<Message, Renderer> + 'a,
+    {
         Element {
             widget: Box::new(widget),
+            #[cfg(feature = "debug")]
+            name: std::any::type_name::<W>(),
         }
     }
 
@@ -179,6 +184,8 @@ where
     {
         Element {
             widget: Box::new(Map::new(self.widget, f)),
+            #[cfg(feature = "debug")]
+            name: self.name,
         }
     }
 
@@ -197,8 +204,13 @@ where
         Message: 'static,
         Renderer: 'a + layout::Debugger,
     {
+        #[cfg(feature = "debug")]
+        let name = self.name;
+
         Element {
             widget: Box::new(Explain::new(self, color.into())),
+            #[cfg(feature = "debug")]
+            name,
         }
     }
 
@@ -225,7 +237,26 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.widget.layout(renderer, limits)
+        // The layout inspector of the debug overlay needs to know which
+        // widget produced every node
+        #[cfg(feature = "debug")]
+        {
+            let mut node = self.widget.layout(renderer, limits);
+
+            node.set_info(layout::Info {
+                widget: self.name,
+                width: self.widget.width(),
+                height: self.widget.height(),
+                limits: *limits,
+            });
+
+            node
+        }
+
+        #[cfg(not(feature = "debug"))]
+        {
+            self.widget.layout(renderer, limits)
+        }
     }
 
     /// Processes a runtime [`Event`].
@@ -282,6 +313,14 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         self.widget.overlay(layout)
     }
+
+    /// Returns whether the [`Element`], or any of its children, has the
+    /// keyboard focus.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn is_focused(&self) -> bool {
+        self.widget.is_focused()
+    }
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -377,6 +416,10 @@ where
             .overlay(layout)
             .map(move |overlay| overlay.map(mapper))
     }
+
+    fn is_focused(&self) -> bool {
+        self.widget.is_focused()
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -461,4 +504,8 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.element.overlay(layout)
     }
+
+    fn is_focused(&self) -> bool {
+        self.element.is_focused()
+    }
 }
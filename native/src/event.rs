@@ -1,4 +1,4 @@
-use crate::{keyboard, mouse, window};
+use crate::{keyboard, mouse, touch, window};
 
 /// A user interface event.
 ///
@@ -7,6 +7,7 @@ use crate::{keyboard, mouse, window};
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
@@ -16,4 +17,7 @@ pub enum Event {
 
     /// A window event
     Window(window::Event),
+
+    /// A touch event
+    Touch(touch::Event),
 }
@@ -4,10 +4,12 @@ mod limits;
 mod node;
 
 pub mod flex;
+pub mod grid;
+pub mod wrap;
 
 pub use debugger::Debugger;
 pub use limits::Limits;
-pub use node::Node;
+pub use node::{Info, Node};
 
 use crate::{Point, Rectangle, Vector};
 
@@ -72,4 +74,16 @@ impl<'a> Layout<'a> {
             )
         })
     }
+
+    /// Returns the [`Info`] of the widget that produced the [`Node`] of the
+    /// [`Layout`], if known.
+    ///
+    /// It is only collected when the `debug` feature is enabled.
+    ///
+    /// [`Info`]: struct.Info.html
+    /// [`Node`]: struct.Node.html
+    /// [`Layout`]: struct.Layout.html
+    pub fn info(&self) -> Option<&'a Info> {
+        self.node.info()
+    }
 }
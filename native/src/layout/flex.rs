@@ -18,11 +18,11 @@
 // limitations under the License.
 use crate::{
     layout::{Limits, Node},
-    Align, Element, Point, Size,
+    Align, Element, Length, Padding, Point, Size,
 };
 
 /// The main axis of a flex layout.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Axis {
     /// The horizontal axis
     Horizontal,
@@ -32,21 +32,21 @@ pub enum Axis {
 }
 
 impl Axis {
-    fn main(&self, size: Size) -> f32 {
+    pub(crate) fn main(&self, size: Size) -> f32 {
         match self {
             Axis::Horizontal => size.width,
             Axis::Vertical => size.height,
         }
     }
 
-    fn cross(&self, size: Size) -> f32 {
+    pub(crate) fn cross(&self, size: Size) -> f32 {
         match self {
             Axis::Horizontal => size.height,
             Axis::Vertical => size.width,
         }
     }
 
-    fn pack(&self, main: f32, cross: f32) -> (f32, f32) {
+    pub(crate) fn pack(&self, main: f32, cross: f32) -> (f32, f32) {
         match self {
             Axis::Horizontal => (main, cross),
             Axis::Vertical => (cross, main),
@@ -64,7 +64,7 @@ pub fn resolve<Message, Renderer>(
     axis: Axis,
     renderer: &Renderer,
     limits: &Limits,
-    padding: f32,
+    padding: Padding,
     spacing: f32,
     align_items: Align,
     items: &[Element<'_, Message, Renderer>],
@@ -84,14 +84,22 @@ where
     nodes.resize(items.len(), Node::default());
 
     for (i, child) in items.iter().enumerate() {
-        let fill_factor = match axis {
+        let length = match axis {
             Axis::Horizontal => child.width(),
             Axis::Vertical => child.height(),
-        }
-        .fill_factor();
+        };
+
+        let fill_factor = length.fill_factor();
 
         if fill_factor == 0 {
-            let (max_width, max_height) = axis.pack(available, max_cross);
+            // Percentages are relative to the whole flex, not to the space
+            // left by previous items
+            let max_main = match length {
+                Length::Percent(_) => axis.main(limits.max()) - total_spacing,
+                _ => available,
+            };
+
+            let (max_width, max_height) = axis.pack(max_main, max_cross);
 
             let child_limits =
                 Limits::new(Size::ZERO, Size::new(max_width, max_height));
@@ -143,14 +151,17 @@ where
         }
     }
 
-    let mut main = padding;
+    let (main_padding, cross_padding) =
+        axis.pack(f32::from(padding.left), f32::from(padding.top));
+
+    let mut main = main_padding;
 
     for (i, node) in nodes.iter_mut().enumerate() {
         if i > 0 {
             main += spacing;
         }
 
-        let (x, y) = axis.pack(main, padding);
+        let (x, y) = axis.pack(main, cross_padding);
 
         node.move_to(Point::new(x, y));
 
@@ -168,7 +179,7 @@ where
         main += axis.main(size);
     }
 
-    let (width, height) = axis.pack(main - padding, cross);
+    let (width, height) = axis.pack(main - main_padding, cross);
     let size = limits.resolve(Size::new(width, height));
 
     Node::with_children(size.pad(padding), nodes)
@@ -0,0 +1,293 @@
+//! Distribute elements in a grid of rows and columns.
+use crate::{
+    layout::{Limits, Node},
+    Align, Element, Length, Padding, Point, Size,
+};
+
+use std::ops::Range;
+
+/// The tracks of one of the axes of a grid, either its rows or its columns.
+#[derive(Debug, Clone, Copy)]
+pub struct Tracks<'a> {
+    /// The strategy used to size every track.
+    ///
+    /// Tracks without a defined [`Length`] use [`Length::Shrink`].
+    ///
+    /// [`Length`]: ../../enum.Length.html
+    /// [`Length::Shrink`]: ../../enum.Length.html#variant.Shrink
+    pub lengths: &'a [Length],
+
+    /// The space _between_ tracks.
+    pub spacing: f32,
+}
+
+/// The placement of an element in a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Placement {
+    /// The index of the first row of the element
+    pub row: usize,
+
+    /// The index of the first column of the element
+    pub column: usize,
+
+    /// The amount of rows spanned by the element
+    pub row_span: usize,
+
+    /// The amount of columns spanned by the element
+    pub column_span: usize,
+
+    /// The horizontal alignment of the element in its cell
+    pub align_x: Align,
+
+    /// The vertical alignment of the element in its cell
+    pub align_y: Align,
+}
+
+impl Placement {
+    fn rows(&self) -> Range<usize> {
+        self.row..self.row + self.row_span.max(1)
+    }
+
+    fn columns(&self) -> Range<usize> {
+        self.column..self.column + self.column_span.max(1)
+    }
+}
+
+/// Computes the grid layout with the given limits, tracks and placements,
+/// applying padding and spacing as needed.
+///
+/// The items are placed in the cells of their [`Placement`], which share
+/// the same index. It returns a new layout [`Node`].
+///
+/// [`Placement`]: struct.Placement.html
+/// [`Node`]: ../struct.Node.html
+pub fn resolve<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    columns: Tracks<'_>,
+    rows: Tracks<'_>,
+    placements: &[Placement],
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.pad(padding);
+    let max = limits.max();
+
+    let column_lengths = lengths(
+        columns.lengths,
+        placements.iter().map(|placement| placement.columns().end),
+    );
+
+    let row_lengths = lengths(
+        rows.lengths,
+        placements.iter().map(|placement| placement.rows().end),
+    );
+
+    // The widths of the columns only depend on the elements spanning a
+    // column that shrinks
+    let column_requirements: Vec<_> = placements
+        .iter()
+        .zip(items)
+        .filter(|(placement, item)| {
+            item.width().fill_factor() == 0
+                && is_intrinsic(&column_lengths, placement.columns(), max.width)
+        })
+        .map(|(placement, item)| {
+            let layout = item.layout(renderer, &Limits::new(Size::ZERO, max));
+
+            (placement.columns(), layout.size().width)
+        })
+        .collect();
+
+    let widths = sizes(
+        &column_lengths,
+        columns.spacing,
+        max.width,
+        &column_requirements,
+    );
+
+    // The heights of the rows depend on the final widths of the columns
+    let row_requirements: Vec<_> = placements
+        .iter()
+        .zip(items)
+        .filter(|(placement, item)| {
+            item.height().fill_factor() == 0
+                && is_intrinsic(&row_lengths, placement.rows(), max.height)
+        })
+        .map(|(placement, item)| {
+            let width = span(&widths, placement.columns(), columns.spacing);
+
+            let layout = item.layout(
+                renderer,
+                &Limits::new(Size::ZERO, Size::new(width, max.height)),
+            );
+
+            (placement.rows(), layout.size().height)
+        })
+        .collect();
+
+    let heights =
+        sizes(&row_lengths, rows.spacing, max.height, &row_requirements);
+
+    let nodes = placements
+        .iter()
+        .zip(items)
+        .map(|(placement, item)| {
+            let x = f32::from(padding.left)
+                + span(&widths, 0..placement.column, columns.spacing)
+                + if placement.column > 0 {
+                    columns.spacing
+                } else {
+                    0.0
+                };
+
+            let y = f32::from(padding.top)
+                + span(&heights, 0..placement.row, rows.spacing)
+                + if placement.row > 0 { rows.spacing } else { 0.0 };
+
+            let area = Size::new(
+                span(&widths, placement.columns(), columns.spacing),
+                span(&heights, placement.rows(), rows.spacing),
+            );
+
+            let mut node =
+                item.layout(renderer, &Limits::new(Size::ZERO, area));
+
+            node.move_to(Point::new(x, y));
+            node.align(placement.align_x, placement.align_y, area);
+
+            node
+        })
+        .collect();
+
+    let size = limits.resolve(Size::new(
+        span(&widths, 0..widths.len(), columns.spacing),
+        span(&heights, 0..heights.len(), rows.spacing),
+    ));
+
+    Node::with_children(size.pad(padding), nodes)
+}
+
+/// Completes the given track lengths up to the last track used.
+fn lengths(
+    lengths: &[Length],
+    ends: impl Iterator<Item = usize>,
+) -> Vec<Length> {
+    let count = ends.max().unwrap_or(0).max(lengths.len());
+
+    (0..count)
+        .map(|i| lengths.get(i).copied().unwrap_or(Length::Shrink))
+        .collect()
+}
+
+/// Returns whether a track is sized by its contents.
+///
+/// Tracks that fill the available space, or a percentage of it, behave like
+/// shrinking ones when the space is unbounded.
+fn is_intrinsic(
+    lengths: &[Length],
+    range: Range<usize>,
+    available: f32,
+) -> bool {
+    lengths[range].iter().any(|length| match length {
+        Length::Shrink => true,
+        Length::Units(_) => false,
+        _ => available.is_infinite(),
+    })
+}
+
+/// Returns the total size of a range of tracks, including the spacing
+/// between them.
+fn span(sizes: &[f32], range: Range<usize>, spacing: f32) -> f32 {
+    let count = range.len();
+
+    sizes[range].iter().sum::<f32>() + spacing * count.saturating_sub(1) as f32
+}
+
+/// Computes the size of every track given the size required by the elements
+/// spanning each range of tracks.
+fn sizes(
+    lengths: &[Length],
+    spacing: f32,
+    available: f32,
+    requirements: &[(Range<usize>, f32)],
+) -> Vec<f32> {
+    let is_flexible =
+        |length: &Length| length.fill_factor() != 0 && available.is_finite();
+
+    let is_fixed = |length: &Length| match length {
+        Length::Units(_) => true,
+        Length::Percent(_) => available.is_finite(),
+        _ => false,
+    };
+
+    // Percentages are relative to the available space without spacing
+    let total_spacing = spacing * lengths.len().saturating_sub(1) as f32;
+
+    let mut sizes: Vec<f32> = lengths
+        .iter()
+        .map(|length| match length {
+            Length::Units(units) => f32::from(*units),
+            Length::Percent(percent) if available.is_finite() => {
+                (available - total_spacing).max(0.0) * f32::from(*percent)
+                    / 100.0
+            }
+            _ => 0.0,
+        })
+        .collect();
+
+    // Elements in a single track grow it directly
+    for (range, size) in
+        requirements.iter().filter(|(range, _)| range.len() == 1)
+    {
+        let track = &mut sizes[range.start];
+
+        *track = track.max(*size);
+    }
+
+    // Elements spanning multiple tracks distribute their missing size evenly
+    // among the shrinking tracks they span, unless a flexible track can
+    // absorb it
+    for (range, size) in requirements.iter().filter(|(range, _)| {
+        range.len() > 1 && !lengths[range.clone()].iter().any(is_flexible)
+    }) {
+        let missing = size - span(&sizes, range.clone(), spacing);
+
+        let shrinking: Vec<usize> = range
+            .clone()
+            .filter(|i| !is_flexible(&lengths[*i]) && !is_fixed(&lengths[*i]))
+            .collect();
+
+        if missing > 0.0 && !shrinking.is_empty() {
+            let share = missing / shrinking.len() as f32;
+
+            for i in shrinking {
+                sizes[i] += share;
+            }
+        }
+    }
+
+    // Flexible tracks share the remaining space
+    let fill_sum: u16 = lengths
+        .iter()
+        .filter(|length| is_flexible(length))
+        .map(Length::fill_factor)
+        .sum();
+
+    if fill_sum > 0 {
+        let remaining =
+            (available - span(&sizes, 0..sizes.len(), spacing)).max(0.0);
+
+        for (size, length) in sizes.iter_mut().zip(lengths) {
+            if is_flexible(length) {
+                *size = remaining * f32::from(length.fill_factor())
+                    / f32::from(fill_sum);
+            }
+        }
+    }
+
+    sizes
+}
@@ -1,4 +1,4 @@
-use crate::{Length, Size};
+use crate::{Length, Padding, Size};
 
 /// A set of size constraints for layouting.
 #[derive(Debug, Clone, Copy)]
@@ -71,6 +71,18 @@ impl Limits {
                 self.max.width = new_width;
                 self.fill.width = new_width;
             }
+            Length::Percent(percent) if self.max.width.is_finite() => {
+                let new_width = (self.max.width * f32::from(percent) / 100.0)
+                    .min(self.max.width)
+                    .max(self.min.width);
+
+                self.min.width = new_width;
+                self.max.width = new_width;
+                self.fill.width = new_width;
+            }
+            Length::Percent(_) => {
+                self.fill.width = self.min.width;
+            }
         }
 
         self
@@ -95,6 +107,18 @@ impl Limits {
                 self.max.height = new_height;
                 self.fill.height = new_height;
             }
+            Length::Percent(percent) if self.max.height.is_finite() => {
+                let new_height = (self.max.height * f32::from(percent) / 100.0)
+                    .min(self.max.height)
+                    .max(self.min.height);
+
+                self.min.height = new_height;
+                self.max.height = new_height;
+                self.fill.height = new_height;
+            }
+            Length::Percent(_) => {
+                self.fill.height = self.min.height;
+            }
         }
 
         self
@@ -143,8 +167,11 @@ impl Limits {
     /// Shrinks the current [`Limits`] to account for the given padding.
     ///
     /// [`Limits`]: struct.Limits.html
-    pub fn pad(&self, padding: f32) -> Limits {
-        self.shrink(Size::new(padding * 2.0, padding * 2.0))
+    pub fn pad(&self, padding: Padding) -> Limits {
+        self.shrink(Size::new(
+            f32::from(padding.horizontal()),
+            f32::from(padding.vertical()),
+        ))
     }
 
     /// Shrinks the current [`Limits`] by the given [`Size`].
@@ -197,4 +224,101 @@ impl Limits {
                 .max(self.fill.height),
         )
     }
+
+    /// Computes the resulting [`Size`] that fits the [`Limits`] given the
+    /// intrinsic size of some content, keeping the given aspect ratio.
+    ///
+    /// The height is derived from the resolved width. If it does not fit,
+    /// the width is derived from the closest height that fits instead.
+    /// Fixed sizes always win over the aspect ratio.
+    ///
+    /// [`Size`]: ../struct.Size.html
+    /// [`Limits`]: struct.Limits.html
+    pub fn resolve_aspect_ratio(
+        &self,
+        intrinsic_size: Size,
+        aspect_ratio: f32,
+    ) -> Size {
+        let size = self.resolve(intrinsic_size);
+
+        let height = (size.width / aspect_ratio)
+            .min(self.max.height)
+            .max(self.min.height);
+
+        let width = (height * aspect_ratio)
+            .min(self.max.width)
+            .max(self.min.width);
+
+        Size::new(width, height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(max_width: f32, max_height: f32) -> Limits {
+        Limits::new(Size::ZERO, Size::new(max_width, max_height))
+    }
+
+    #[test]
+    fn percent_width_takes_a_share_of_the_maximum() {
+        let size = limits(200.0, 100.0)
+            .width(Length::Percent(30))
+            .height(Length::Shrink)
+            .resolve(Size::ZERO);
+
+        assert_eq!(size.width, 60.0);
+    }
+
+    #[test]
+    fn percent_width_is_clamped_to_the_limits() {
+        let limits = Limits::new(Size::new(80.0, 0.0), Size::new(200.0, 100.0));
+
+        let small = limits.width(Length::Percent(10)).resolve(Size::ZERO);
+        let large = limits.width(Length::Percent(150)).resolve(Size::ZERO);
+
+        assert_eq!(small.width, 80.0);
+        assert_eq!(large.width, 200.0);
+    }
+
+    #[test]
+    fn percent_width_shrinks_without_a_maximum() {
+        let size = Limits::NONE
+            .width(Length::Percent(50))
+            .height(Length::Shrink)
+            .resolve(Size::new(40.0, 10.0));
+
+        assert_eq!(size.width, 40.0);
+    }
+
+    #[test]
+    fn aspect_ratio_derives_the_height_from_the_width() {
+        let size = limits(200.0, 200.0)
+            .width(Length::Fill)
+            .height(Length::Shrink)
+            .resolve_aspect_ratio(Size::new(10.0, 10.0), 2.0);
+
+        assert_eq!(size, Size::new(200.0, 100.0));
+    }
+
+    #[test]
+    fn aspect_ratio_derives_the_width_when_the_height_does_not_fit() {
+        let size = limits(200.0, 50.0)
+            .width(Length::Fill)
+            .height(Length::Shrink)
+            .resolve_aspect_ratio(Size::new(10.0, 10.0), 2.0);
+
+        assert_eq!(size, Size::new(100.0, 50.0));
+    }
+
+    #[test]
+    fn aspect_ratio_yields_to_fixed_sizes() {
+        let size = limits(200.0, 200.0)
+            .width(Length::Units(100))
+            .height(Length::Units(30))
+            .resolve_aspect_ratio(Size::new(10.0, 10.0), 2.0);
+
+        assert_eq!(size, Size::new(100.0, 30.0));
+    }
 }
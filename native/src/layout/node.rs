@@ -1,10 +1,35 @@
-use crate::{Align, Point, Rectangle, Size};
+use crate::layout::Limits;
+use crate::{Align, Length, Point, Rectangle, Size};
 
 /// The bounds of an element and its children.
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    #[cfg(feature = "debug")]
+    info: Option<Info>,
+}
+
+/// Information about the widget that produced a [`Node`].
+///
+/// It is useful to inspect a layout for debugging purposes.
+///
+/// [`Node`]: struct.Node.html
+#[derive(Debug, Clone, Copy)]
+pub struct Info {
+    /// The type name of the widget
+    pub widget: &'static str,
+
+    /// The width of the widget
+    pub width: Length,
+
+    /// The height of the widget
+    pub height: Length,
+
+    /// The [`Limits`] the widget was laid out with
+    ///
+    /// [`Limits`]: struct.Limits.html
+    pub limits: Limits,
 }
 
 impl Node {
@@ -29,6 +54,8 @@ impl Node {
                 height: size.height,
             },
             children,
+            #[cfg(feature = "debug")]
+            info: None,
         }
     }
 
@@ -54,6 +81,34 @@ impl Node {
         &self.children
     }
 
+    /// Returns the [`Info`] of the widget that produced the [`Node`], if
+    /// known.
+    ///
+    /// It is only collected when the `debug` feature is enabled.
+    ///
+    /// [`Info`]: struct.Info.html
+    /// [`Node`]: struct.Node.html
+    pub fn info(&self) -> Option<&Info> {
+        #[cfg(feature = "debug")]
+        {
+            self.info.as_ref()
+        }
+
+        #[cfg(not(feature = "debug"))]
+        {
+            None
+        }
+    }
+
+    /// Attaches the [`Info`] of the widget that produced the [`Node`].
+    ///
+    /// [`Info`]: struct.Info.html
+    /// [`Node`]: struct.Node.html
+    #[cfg(feature = "debug")]
+    pub(crate) fn set_info(&mut self, info: Info) {
+        self.info = Some(info);
+    }
+
     /// Aligns the [`Node`] in the given space.
     ///
     /// [`Node`]: struct.Node.html
@@ -0,0 +1,111 @@
+//! Distribute elements in lines that wrap when running out of space.
+use crate::{
+    layout::{flex::Axis, Limits, Node},
+    Align, Element, Padding, Point, Size,
+};
+
+/// The spacing of a wrapping layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spacing {
+    /// The space _between_ the elements of a line.
+    pub items: f32,
+
+    /// The space _between_ lines.
+    pub lines: f32,
+}
+
+/// Computes the wrapping layout with the given axis and limits, applying
+/// spacing, padding and alignment to the items as needed.
+///
+/// Items are placed along the main axis until they do not fit in the
+/// available space. Then, a new line is started. Every item is aligned in
+/// the cross axis of its line.
+///
+/// It returns a new layout [`Node`].
+///
+/// [`Node`]: ../struct.Node.html
+pub fn resolve<Message, Renderer>(
+    axis: Axis,
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    spacing: Spacing,
+    align_items: Align,
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.pad(padding);
+    let max_main = axis.main(limits.max());
+    let child_limits = Limits::new(Size::ZERO, limits.max());
+
+    let mut nodes: Vec<Node> = Vec::with_capacity(items.len());
+
+    // Every line is defined by its first node and its cross size
+    let mut lines: Vec<(usize, f32)> = Vec::new();
+
+    let mut main = 0.0;
+    let mut max_line_main: f32 = 0.0;
+    let mut cross = 0.0;
+
+    for child in items {
+        let mut node = child.layout(renderer, &child_limits);
+        let size = node.size();
+
+        let is_line_start = match lines.last() {
+            None => true,
+            Some(_) => main + spacing.items + axis.main(size) > max_main,
+        };
+
+        if is_line_start {
+            if let Some((_, line_cross)) = lines.last() {
+                cross += line_cross + spacing.lines;
+            }
+
+            lines.push((nodes.len(), 0.0));
+            main = 0.0;
+        } else {
+            main += spacing.items;
+        }
+
+        let (x, y) = axis.pack(main, cross);
+        node.move_to(Point::new(
+            f32::from(padding.left) + x,
+            f32::from(padding.top) + y,
+        ));
+
+        main += axis.main(size);
+        max_line_main = max_line_main.max(main);
+
+        if let Some((_, line_cross)) = lines.last_mut() {
+            *line_cross = line_cross.max(axis.cross(size));
+        }
+
+        nodes.push(node);
+    }
+
+    if let Some((_, line_cross)) = lines.last() {
+        cross += line_cross;
+    }
+
+    for (i, (start, line_cross)) in lines.iter().enumerate() {
+        let end = lines.get(i + 1).map(|(end, _)| *end).unwrap_or(nodes.len());
+
+        for node in &mut nodes[*start..end] {
+            let (horizontal, vertical) = match axis {
+                Axis::Horizontal => (Align::Start, align_items),
+                Axis::Vertical => (align_items, Align::Start),
+            };
+
+            let (width, height) = axis.pack(0.0, *line_cross);
+
+            node.align(horizontal, vertical, Size::new(width, height));
+        }
+    }
+
+    let (width, height) = axis.pack(max_line_main, cross);
+    let size = limits.resolve(Size::new(width, height));
+
+    Node::with_children(size.pad(padding), nodes)
+}
@@ -34,7 +34,10 @@
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
-#![forbid(rust_2018_idioms)]
+// The `serde` derives allow lints that cannot be allowed once forbidden
+#![cfg_attr(not(feature = "serialize"), forbid(rust_2018_idioms))]
+#![cfg_attr(feature = "serialize", deny(rust_2018_idioms))]
+pub mod animation;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
@@ -42,6 +45,7 @@ pub mod overlay;
 pub mod program;
 pub mod renderer;
 pub mod subscription;
+pub mod touch;
 pub mod widget;
 pub mod window;
 
@@ -62,8 +66,8 @@ mod debug;
 mod debug;
 
 pub use iced_core::{
-    Align, Background, Color, Font, HorizontalAlignment, Length, Point,
-    Rectangle, Size, Vector, VerticalAlignment,
+    Align, Background, Color, Font, HorizontalAlignment, Length, Padding,
+    Point, Rectangle, Size, Vector, VerticalAlignment,
 };
 pub use iced_futures::{executor, futures, Command};
 
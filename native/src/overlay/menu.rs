@@ -1,8 +1,8 @@
 //! Build and show dropdown menus.
 use crate::{
     container, layout, mouse, overlay, scrollable, text, Clipboard, Container,
-    Element, Event, Hasher, Layout, Length, Point, Rectangle, Scrollable, Size,
-    Vector, Widget,
+    Element, Event, Hasher, Layout, Length, Padding, Point, Rectangle,
+    Scrollable, Size, Vector, Widget,
 };
 
 /// A list of selectable options.
@@ -13,7 +13,7 @@ pub struct Menu<'a, T, Renderer: self::Renderer> {
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
     width: u16,
-    padding: u16,
+    padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
     style: <Renderer as self::Renderer>::Style,
@@ -41,7 +41,7 @@ where
             hovered_option,
             last_selection,
             width: 0,
-            padding: 0,
+            padding: Padding::ZERO,
             text_size: None,
             font: Default::default(),
             style: Default::default(),
@@ -59,8 +59,8 @@ where
     /// Sets the padding of the [`Menu`].
     ///
     /// [`Menu`]: struct.Menu.html
-    pub fn padding(mut self, padding: u16) -> Self {
-        self.padding = padding;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -275,7 +275,7 @@ struct List<'a, T, Renderer: self::Renderer> {
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
-    padding: u16,
+    padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
     style: <Renderer as self::Renderer>::Style,
@@ -308,7 +308,7 @@ where
         let size = {
             let intrinsic = Size::new(
                 0.0,
-                f32::from(text_size + self.padding * 2)
+                f32::from(text_size + self.padding.vertical())
                     * self.options.len() as f32,
             );
 
@@ -358,7 +358,7 @@ where
                 if bounds.contains(cursor_position) {
                     *self.hovered_option = Some(
                         ((cursor_position.y - bounds.y)
-                            / f32::from(text_size + self.padding * 2))
+                            / f32::from(text_size + self.padding.vertical()))
                             as usize,
                     );
                 }
@@ -426,7 +426,7 @@ pub trait Renderer:
         cursor_position: Point,
         options: &[T],
         hovered_option: Option<usize>,
-        padding: u16,
+        padding: Padding,
         text_size: u16,
         font: Self::Font,
         style: &<Self as Renderer>::Style,
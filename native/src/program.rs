@@ -1,5 +1,5 @@
 //! Build interactive programs using The Elm Architecture.
-use crate::{Command, Element, Renderer};
+use crate::{keyboard, Command, Element, Renderer};
 
 mod state;
 
@@ -36,4 +36,32 @@ pub trait Program: Sized {
     ///
     /// [`Program`]: trait.Program.html
     fn view(&mut self) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Returns the next moment the [`Program`] needs to be drawn again, even
+    /// if no events or messages are received in the meantime.
+    ///
+    /// This is useful to drive animations kept in the state of the
+    /// [`Program`], like [`Animated`] values.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Program`]: trait.Program.html
+    /// [`Animated`]: ../animation/struct.Animated.html
+    fn next_draw(&self) -> Option<std::time::Instant> {
+        None
+    }
+
+    /// Returns the keyboard shortcuts of the [`Program`], together with the
+    /// __message__ each one of them produces.
+    ///
+    /// Shortcuts are not triggered while a widget has the keyboard focus.
+    /// Otherwise, the key press that triggers a shortcut is not processed by
+    /// the widgets.
+    ///
+    /// By default, it returns no shortcuts.
+    ///
+    /// [`Program`]: trait.Program.html
+    fn shortcuts(&self) -> Vec<(keyboard::Shortcut, Self::Message)> {
+        Vec::new()
+    }
 }
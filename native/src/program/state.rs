@@ -1,3 +1,5 @@
+use crate::keyboard;
+use crate::renderer::request_redraw;
 use crate::{
     Cache, Clipboard, Command, Debug, Event, Point, Program, Renderer, Size,
     UserInterface,
@@ -44,12 +46,18 @@ where
             debug,
         );
 
-        debug.draw_started();
-        let primitive =
-            user_interface.draw(renderer, cursor_position, &mut None);
-        debug.draw_finished();
+        let mut next_draw = None;
+
+        let primitive = draw_user_interface(
+            &mut user_interface,
+            renderer,
+            cursor_position,
+            &mut next_draw,
+            debug,
+        );
 
         let cache = Some(user_interface.into_cache());
+        request_redraw(&mut next_draw, program.next_draw());
 
         State {
             program,
@@ -57,7 +65,7 @@ where
             primitive,
             queued_events: Vec::new(),
             queued_messages: Vec::new(),
-            next_draw: None,
+            next_draw,
         }
     }
 
@@ -108,6 +116,9 @@ where
     /// Processes all the queued events and messages, rebuilding and redrawing
     /// the widgets of the linked [`Program`] if necessary.
     ///
+    /// Key presses trigger the shortcuts of the [`Program`] instead of
+    /// reaching its widgets, unless a widget has the keyboard focus.
+    ///
     /// Returns the [`Command`] obtained from [`Program`] after updating it,
     /// only if an update was necessary.
     ///
@@ -121,6 +132,15 @@ where
         debug: &mut Debug,
     ) -> Option<Command<P::Message>> {
         let mut next_draw = None;
+
+        // The user interface borrows the program, so shortcuts are looked up
+        // before building it
+        let shortcuts: Vec<_> = self
+            .queued_events
+            .iter()
+            .map(|event| shortcut_messages(&self.program, event))
+            .collect();
+
         let mut user_interface = build_user_interface(
             &mut self.program,
             self.cache.take().unwrap(),
@@ -130,24 +150,52 @@ where
         );
 
         debug.event_processing_started();
-        let mut messages = user_interface.update(
-            &self.queued_events,
+        let mut messages = Vec::new();
+        let mut events = Vec::new();
+
+        for (event, shortcut) in self.queued_events.drain(..).zip(shortcuts) {
+            if shortcut.is_empty() {
+                events.push(event);
+                continue;
+            }
+
+            // Earlier events may change the focus
+            messages.extend(user_interface.update(
+                &events,
+                cursor_position,
+                clipboard,
+                renderer,
+            ));
+            events.clear();
+
+            if user_interface.is_focused() {
+                events.push(event);
+            } else {
+                messages.extend(shortcut);
+            }
+        }
+
+        messages.extend(user_interface.update(
+            &events,
             cursor_position,
             clipboard,
             renderer,
-        );
+        ));
         messages.extend(self.queued_messages.drain(..));
-
-        self.queued_events.clear();
         debug.event_processing_finished();
 
         if messages.is_empty() {
-            debug.draw_started();
-            self.primitive =
-                user_interface.draw(renderer, cursor_position, &mut next_draw);
-            debug.draw_finished();
+            self.primitive = draw_user_interface(
+                &mut user_interface,
+                renderer,
+                cursor_position,
+                &mut next_draw,
+                debug,
+            );
 
             self.cache = Some(user_interface.into_cache());
+
+            request_redraw(&mut next_draw, self.program.next_draw());
             self.next_draw = next_draw;
 
             None
@@ -175,12 +223,17 @@ where
                 debug,
             );
 
-            debug.draw_started();
-            self.primitive =
-                user_interface.draw(renderer, cursor_position, &mut next_draw);
-            debug.draw_finished();
+            self.primitive = draw_user_interface(
+                &mut user_interface,
+                renderer,
+                cursor_position,
+                &mut next_draw,
+                debug,
+            );
 
             self.cache = Some(user_interface.into_cache());
+
+            request_redraw(&mut next_draw, self.program.next_draw());
             self.next_draw = next_draw;
 
             Some(commands)
@@ -188,6 +241,24 @@ where
     }
 }
 
+fn shortcut_messages<P: Program>(
+    program: &P,
+    event: &Event,
+) -> Vec<P::Message> {
+    match event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        }) => program
+            .shortcuts()
+            .into_iter()
+            .filter(|(shortcut, _)| shortcut.matches(*key_code, *modifiers))
+            .map(|(_, message)| message)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
 fn build_user_interface<'a, P: Program>(
     program: &'a mut P,
     cache: Cache,
@@ -205,3 +276,125 @@ fn build_user_interface<'a, P: Program>(
 
     user_interface
 }
+
+fn draw_user_interface<Message, Renderer: crate::Renderer>(
+    user_interface: &mut UserInterface<'_, Message, Renderer>,
+    renderer: &mut Renderer,
+    cursor_position: Point,
+    next_draw: &mut Option<std::time::Instant>,
+    debug: &mut Debug,
+) -> Renderer::Output {
+    debug.draw_started();
+    let primitive = user_interface.draw(renderer, cursor_position, next_draw);
+    debug.draw_finished();
+
+    debug.inspect(user_interface.layout(), cursor_position);
+
+    match debug.inspected_bounds() {
+        Some(bounds) => renderer.highlight(primitive, bounds),
+        None => primitive,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::{KeyCode, ModifiersState};
+    use crate::renderer::Null;
+    use crate::{mouse, text_input, Element, TextInput};
+
+    #[derive(Debug, Clone)]
+    enum Message {
+        Save,
+        Changed(String),
+    }
+
+    #[derive(Default)]
+    struct Editor {
+        input: text_input::State,
+        value: String,
+        saves: usize,
+    }
+
+    impl Program for Editor {
+        type Renderer = Null;
+        type Message = Message;
+
+        fn update(&mut self, message: Message) -> Command<Message> {
+            match message {
+                Message::Save => self.saves += 1,
+                Message::Changed(value) => self.value = value,
+            }
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Null> {
+            TextInput::new(&mut self.input, "", &self.value, Message::Changed)
+                .into()
+        }
+
+        fn shortcuts(&self) -> Vec<(keyboard::Shortcut, Message)> {
+            vec![("Ctrl+S".parse().unwrap(), Message::Save)]
+        }
+    }
+
+    fn update(state: &mut State<Editor>, events: Vec<Event>) {
+        let mut renderer = Null::new();
+
+        for event in events {
+            state.queue_event(event);
+        }
+
+        let _ = state.update(
+            Size::new(100.0, 100.0),
+            Point::new(5.0, 5.0),
+            None,
+            &mut renderer,
+            &mut Debug::new(),
+        );
+    }
+
+    fn editor() -> State<Editor> {
+        State::new(
+            Editor::default(),
+            Size::new(100.0, 100.0),
+            Point::new(5.0, 5.0),
+            &mut Null::new(),
+            &mut Debug::new(),
+        )
+    }
+
+    fn save() -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: KeyCode::S,
+            modifiers: ModifiersState {
+                control: true,
+                ..ModifiersState::default()
+            },
+        })
+    }
+
+    fn click() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    #[test]
+    fn shortcuts_are_triggered_without_focus() {
+        let mut state = editor();
+
+        update(&mut state, vec![save(), save()]);
+
+        assert_eq!(state.program().saves, 2);
+    }
+
+    #[test]
+    fn shortcuts_are_not_triggered_while_focused() {
+        let mut state = editor();
+
+        update(&mut state, vec![save(), click(), save()]);
+
+        assert!(state.program().input.is_focused());
+        assert_eq!(state.program().saves, 1);
+    }
+}
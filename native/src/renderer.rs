@@ -19,6 +19,15 @@
 //! [`text::Renderer`]: ../widget/text/trait.Renderer.html
 //! [`Checkbox`]: ../widget/checkbox/struct.Checkbox.html
 //! [`checkbox::Renderer`]: ../widget/checkbox/trait.Renderer.html
+//!
+//! # Redraws
+//! Widgets that change over time request a future redraw by calling
+//! [`request_redraw`] with the `draw_at` argument of [`Widget::draw`]. Only
+//! the earliest request is kept, which means containers can simply pass
+//! `draw_at` down to all of their children.
+//!
+//! [`request_redraw`]: fn.request_redraw.html
+//! [`Widget::draw`]: ../widget/trait.Widget.html#tymethod.draw
 
 #[cfg(debug_assertions)]
 mod null;
@@ -27,6 +36,18 @@ pub use null::Null;
 
 use crate::{layout, Element, Rectangle};
 
+use std::time::Instant;
+
+/// Requests a redraw of the user interface at the given moment, if any.
+///
+/// The earliest request is kept in `draw_at`.
+pub fn request_redraw(draw_at: &mut Option<Instant>, at: Option<Instant>) {
+    *draw_at = match (*draw_at, at) {
+        (Some(current), Some(at)) => Some(current.min(at)),
+        (current, at) => current.or(at),
+    };
+}
+
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
 pub trait Renderer: Sized {
@@ -65,4 +86,27 @@ pub trait Renderer: Sized {
         overlay: Self::Output,
         overlay_bounds: Rectangle,
     ) -> Self::Output;
+
+    /// Takes the bounds of the text cursor of the focused text input that
+    /// was drawn last, if any.
+    ///
+    /// Shells use it to place the candidate window of an input method next
+    /// to the text being composed. By default, it returns `None`.
+    fn take_ime_area(&mut self) -> Option<Rectangle> {
+        None
+    }
+
+    /// Highlights the given bounds on top of the `base` output.
+    ///
+    /// The layout inspector of [`Debug`] uses it to show the widget under
+    /// the cursor. By default, it returns the `base` output unchanged.
+    ///
+    /// [`Debug`]: ../struct.Debug.html
+    fn highlight(
+        &mut self,
+        base: Self::Output,
+        _bounds: Rectangle,
+    ) -> Self::Output {
+        base
+    }
 }
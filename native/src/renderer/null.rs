@@ -1,8 +1,8 @@
 use crate::{
-    button, checkbox, column, container, pane_grid, progress_bar, radio, row,
-    scrollable, slider, text, text_input, Color, Element, Font,
-    HorizontalAlignment, Layout, Point, Rectangle, Renderer, Size,
-    VerticalAlignment,
+    button, checkbox, column, container, grid, pane_grid, progress_bar, radio,
+    row, scrollable, slider, spinner, stack, text, text_input, toggler, wrap,
+    Color, Element, Font, HorizontalAlignment, Layout, Padding, Point,
+    Rectangle, Renderer, Size, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
@@ -40,6 +40,42 @@ impl column::Renderer for Null {
     }
 }
 
+impl grid::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _draw_at: &mut Option<std::time::Instant>,
+    ) {
+    }
+}
+
+impl stack::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _draw_at: &mut Option<std::time::Instant>,
+    ) {
+    }
+}
+
+impl wrap::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _draw_at: &mut Option<std::time::Instant>,
+    ) {
+    }
+}
+
 impl row::Renderer for Null {
     fn draw<Message>(
         &mut self,
@@ -145,7 +181,7 @@ impl text_input::Renderer for Null {
 }
 
 impl button::Renderer for Null {
-    const DEFAULT_PADDING: u16 = 0;
+    const DEFAULT_PADDING: Padding = Padding::ZERO;
 
     type Style = ();
 
@@ -181,6 +217,24 @@ impl radio::Renderer for Null {
     }
 }
 
+impl toggler::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 20;
+    const DEFAULT_SPACING: u16 = 15;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _is_active: bool,
+        _knob_position: f32,
+        _is_mouse_over: bool,
+        _label: Self::Output,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
 impl checkbox::Renderer for Null {
     type Style = ();
 
@@ -228,6 +282,22 @@ impl progress_bar::Renderer for Null {
         _style: &Self::Style,
     ) {
     }
+
+    fn draw_indeterminate(
+        &self,
+        _bounds: Rectangle,
+        _cycle: f32,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl spinner::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 40;
+
+    fn draw(&mut self, _bounds: Rectangle, _cycle: f32, _style: &Self::Style) {}
 }
 
 impl container::Renderer for Null {
@@ -31,9 +31,14 @@ pub type Tracker = iced_futures::subscription::Tracker<Hasher, Event>;
 
 pub use iced_futures::subscription::Recipe;
 
+pub mod channel {
+    //! Push messages into an application from anywhere.
+    pub use iced_futures::subscription::channel::{Output, Sender};
+}
+
 mod events;
 
-use events::Events;
+use events::{Events, EventsWith};
 
 /// Returns a [`Subscription`] to all the runtime events.
 ///
@@ -45,3 +50,58 @@ use events::Events;
 pub fn events() -> Subscription<Event> {
     Subscription::from_recipe(Events)
 }
+
+/// Returns a [`Subscription`] that filters all the runtime events with the
+/// provided function, producing messages accordingly.
+///
+/// This subscription will call the provided function for every [`Event`]
+/// handled by the runtime. If the function:
+///
+/// - Returns `None`, the [`Event`] will be discarded.
+/// - Returns `Some` message, the `Message` will be produced.
+///
+/// The function is part of the identity of the [`Subscription`].
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`Event`]: ../enum.Event.html
+pub fn events_with<Message>(
+    f: fn(Event) -> Option<Message>,
+) -> Subscription<Message>
+where
+    Message: 'static + Send,
+{
+    Subscription::from_recipe(EventsWith { f })
+}
+
+/// Returns a [`Subscription`] that opens a channel and produces every value
+/// sent through it.
+///
+/// The first output is [`channel::Output::Ready`], which contains the
+/// [`channel::Sender`] you can use to push values from other threads or
+/// library callbacks. The channel stays open as long as you keep returning a
+/// [`Subscription`] with the same `id`.
+///
+/// # Example
+/// ```
+/// use iced_native::subscription::{self, channel, Subscription};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Worker(channel::Output<u32>),
+/// }
+///
+/// fn subscription() -> Subscription<Message> {
+///     subscription::channel("worker").map(Message::Worker)
+/// }
+/// ```
+///
+/// [`Subscription`]: type.Subscription.html
+/// [`channel::Output::Ready`]: channel/enum.Output.html#variant.Ready
+/// [`channel::Sender`]: channel/type.Sender.html
+pub fn channel<I, T>(id: I) -> Subscription<channel::Output<T>>
+where
+    I: std::hash::Hash + 'static,
+    T: Send + 'static,
+{
+    iced_futures::subscription::channel(id)
+}
@@ -2,6 +2,8 @@ use crate::{
     subscription::{EventStream, Recipe},
     Event, Hasher,
 };
+use iced_futures::futures::future;
+use iced_futures::futures::StreamExt;
 use iced_futures::BoxStream;
 
 pub struct Events;
@@ -22,3 +24,33 @@ impl Recipe<Hasher, Event> for Events {
         event_stream
     }
 }
+
+pub struct EventsWith<Message> {
+    pub f: fn(Event) -> Option<Message>,
+}
+
+impl<Message> Recipe<Hasher, Event> for EventsWith<Message>
+where
+    Message: 'static + Send,
+{
+    type Output = Message;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        (self.f as usize).hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        event_stream: EventStream,
+    ) -> BoxStream<Self::Output> {
+        let f = self.f;
+
+        event_stream
+            .filter_map(move |event| future::ready(f(event)))
+            .boxed()
+    }
+}
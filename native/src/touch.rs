@@ -0,0 +1,6 @@
+//! Track touch events.
+
+pub mod gesture;
+
+pub use gesture::Gesture;
+pub use iced_core::touch::*;
@@ -0,0 +1,243 @@
+//! Recognize pan and pinch gestures.
+use crate::touch::{Event, Finger};
+use crate::{Point, Vector};
+
+use std::collections::HashMap;
+
+/// A gesture performed with one or two fingers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// A single finger was dragged.
+    Pan {
+        /// The movement of the finger
+        delta: Vector,
+    },
+
+    /// Two fingers were moved closer or further apart.
+    Pinch {
+        /// The point between both fingers
+        center: Point,
+
+        /// The movement of the center between both fingers
+        delta: Vector,
+
+        /// The ratio between the new and the previous distance of the
+        /// fingers. It is greater than `1.0` when zooming in.
+        scale: f32,
+    },
+}
+
+/// A recognizer of gestures.
+///
+/// Feed it every touch [`Event`] and it will produce a [`Gesture`] whenever
+/// the fingers on the screen perform one. It is meant to be kept in the
+/// state of a widget or a `canvas::Program`.
+///
+/// # Example
+/// ```
+/// use iced_native::touch::{self, gesture, Finger, Gesture};
+/// use iced_native::Point;
+///
+/// let mut recognizer = gesture::Recognizer::new();
+///
+/// let _ = recognizer.update(touch::Event::FingerPressed {
+///     id: Finger(0),
+///     position: Point::new(10.0, 10.0),
+/// });
+///
+/// let gesture = recognizer.update(touch::Event::FingerMoved {
+///     id: Finger(0),
+///     position: Point::new(15.0, 10.0),
+/// });
+///
+/// assert!(matches!(gesture, Some(Gesture::Pan { .. })));
+/// ```
+///
+/// [`Event`]: ../enum.Event.html
+/// [`Gesture`]: enum.Gesture.html
+#[derive(Debug, Clone, Default)]
+pub struct Recognizer {
+    fingers: HashMap<Finger, Point>,
+}
+
+impl Recognizer {
+    /// Creates a new [`Recognizer`] with no fingers on the screen.
+    ///
+    /// [`Recognizer`]: struct.Recognizer.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the amount of fingers currently on the screen.
+    pub fn fingers(&self) -> usize {
+        self.fingers.len()
+    }
+
+    /// Processes a touch [`Event`] and returns the [`Gesture`] it completes,
+    /// if any.
+    ///
+    /// Gestures involving more than two fingers are ignored.
+    ///
+    /// [`Event`]: ../enum.Event.html
+    /// [`Gesture`]: enum.Gesture.html
+    pub fn update(&mut self, event: Event) -> Option<Gesture> {
+        match event {
+            Event::FingerPressed { id, position } => {
+                let _ = self.fingers.insert(id, position);
+
+                None
+            }
+            Event::FingerMoved { id, position } => {
+                let previous = self.fingers.insert(id, position)?;
+
+                match self.fingers.len() {
+                    1 => Some(Gesture::Pan {
+                        delta: position - previous,
+                    }),
+                    2 => {
+                        let other = self
+                            .fingers
+                            .iter()
+                            .find(|(finger, _)| **finger != id)
+                            .map(|(_, position)| *position)?;
+
+                        let old_distance = previous.distance(other);
+
+                        if old_distance <= 0.0 {
+                            return None;
+                        }
+
+                        let old_center = midpoint(previous, other);
+                        let center = midpoint(position, other);
+
+                        Some(Gesture::Pinch {
+                            center,
+                            delta: center - old_center,
+                            scale: position.distance(other) / old_distance,
+                        })
+                    }
+                    _ => None,
+                }
+            }
+            Event::FingerLifted { id, .. } | Event::FingerLost { id, .. } => {
+                let _ = self.fingers.remove(&id);
+
+                None
+            }
+        }
+    }
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(recognizer: &mut Recognizer, finger: u64, x: f32, y: f32) {
+        assert_eq!(
+            recognizer.update(Event::FingerPressed {
+                id: Finger(finger),
+                position: Point::new(x, y),
+            }),
+            None
+        );
+    }
+
+    fn drag(
+        recognizer: &mut Recognizer,
+        finger: u64,
+        x: f32,
+        y: f32,
+    ) -> Option<Gesture> {
+        recognizer.update(Event::FingerMoved {
+            id: Finger(finger),
+            position: Point::new(x, y),
+        })
+    }
+
+    #[test]
+    fn one_finger_pans() {
+        let mut recognizer = Recognizer::new();
+
+        press(&mut recognizer, 0, 10.0, 10.0);
+
+        assert_eq!(
+            drag(&mut recognizer, 0, 15.0, 5.0),
+            Some(Gesture::Pan {
+                delta: Vector::new(5.0, -5.0)
+            })
+        );
+    }
+
+    #[test]
+    fn two_fingers_pinch() {
+        let mut recognizer = Recognizer::new();
+
+        press(&mut recognizer, 0, 0.0, 0.0);
+        press(&mut recognizer, 1, 10.0, 0.0);
+
+        assert_eq!(
+            drag(&mut recognizer, 1, 20.0, 0.0),
+            Some(Gesture::Pinch {
+                center: Point::new(10.0, 0.0),
+                delta: Vector::new(5.0, 0.0),
+                scale: 2.0,
+            })
+        );
+    }
+
+    #[test]
+    fn fingers_on_the_same_spot_do_not_pinch() {
+        let mut recognizer = Recognizer::new();
+
+        press(&mut recognizer, 0, 5.0, 5.0);
+        press(&mut recognizer, 1, 5.0, 5.0);
+
+        assert_eq!(drag(&mut recognizer, 1, 10.0, 5.0), None);
+    }
+
+    #[test]
+    fn three_fingers_are_ignored() {
+        let mut recognizer = Recognizer::new();
+
+        press(&mut recognizer, 0, 0.0, 0.0);
+        press(&mut recognizer, 1, 10.0, 0.0);
+        press(&mut recognizer, 2, 20.0, 0.0);
+
+        assert_eq!(drag(&mut recognizer, 2, 30.0, 0.0), None);
+    }
+
+    #[test]
+    fn lifted_and_lost_fingers_are_forgotten() {
+        let mut recognizer = Recognizer::new();
+
+        press(&mut recognizer, 0, 0.0, 0.0);
+        press(&mut recognizer, 1, 10.0, 0.0);
+
+        assert_eq!(
+            recognizer.update(Event::FingerLost {
+                id: Finger(1),
+                position: Point::new(10.0, 0.0),
+            }),
+            None
+        );
+        assert_eq!(recognizer.fingers(), 1);
+        assert!(matches!(
+            drag(&mut recognizer, 0, 5.0, 0.0),
+            Some(Gesture::Pan { .. })
+        ));
+
+        assert_eq!(
+            recognizer.update(Event::FingerLifted {
+                id: Finger(0),
+                position: Point::new(5.0, 0.0),
+            }),
+            None
+        );
+        assert_eq!(recognizer.fingers(), 0);
+        assert_eq!(drag(&mut recognizer, 0, 10.0, 0.0), None);
+    }
+}
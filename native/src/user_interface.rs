@@ -387,6 +387,23 @@ where
         }
     }
 
+    /// Returns the [`Layout`] of the widgets of the [`UserInterface`],
+    /// excluding any overlay.
+    ///
+    /// [`Layout`]: layout/struct.Layout.html
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.base.layout)
+    }
+
+    /// Returns whether a widget of the [`UserInterface`] has the keyboard
+    /// focus.
+    ///
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn is_focused(&self) -> bool {
+        self.root.is_focused()
+    }
+
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
     /// process.
     ///
@@ -24,19 +24,25 @@ pub mod button;
 pub mod checkbox;
 pub mod column;
 pub mod container;
+pub mod grid;
 pub mod image;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod responsive;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod space;
+pub mod spinner;
+pub mod stack;
 pub mod svg;
 pub mod text;
 pub mod text_input;
+pub mod toggler;
+pub mod wrap;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -47,6 +53,8 @@ pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
 pub use pane_grid::PaneGrid;
@@ -57,6 +65,8 @@ pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use responsive::Responsive;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
@@ -67,11 +77,19 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use toggler::Toggler;
+#[doc(no_inline)]
+pub use wrap::Wrap;
 
 use crate::{layout, overlay, Clipboard, Event, Hasher, Layout, Length, Point};
 
@@ -130,7 +148,12 @@ where
 
     /// Draws the [`Widget`] using the associated `Renderer`.
     ///
+    /// A [`Widget`] that changes over time can ask to be drawn again by
+    /// passing `draw_at` to [`renderer::request_redraw`]. Widgets with
+    /// children must pass `draw_at` down when drawing them.
+    ///
     /// [`Widget`]: trait.Widget.html
+    /// [`renderer::request_redraw`]: ../renderer/fn.request_redraw.html
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -192,4 +215,17 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         None
     }
+
+    /// Returns whether the [`Widget`], or any of its children, has the
+    /// keyboard focus.
+    ///
+    /// Keyboard shortcuts are not triggered while a widget has the focus, so
+    /// widgets holding other widgets should ask their children.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    fn is_focused(&self) -> bool {
+        false
+    }
 }
@@ -5,8 +5,8 @@
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
 use crate::{
-    layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Widget,
+    layout, mouse, Clipboard, Element, Event, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Widget,
 };
 use std::hash::Hash;
 
@@ -35,7 +35,7 @@ pub struct Button<'a, Message, Renderer: self::Renderer> {
     height: Length,
     min_width: u32,
     min_height: u32,
-    padding: u16,
+    padding: Padding,
     style: Renderer::Style,
 }
 
@@ -100,8 +100,8 @@ where
     /// Sets the padding of the [`Button`].
     ///
     /// [`Button`]: struct.Button.html
-    pub fn padding(mut self, padding: u16) -> Self {
-        self.padding = padding;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -158,7 +158,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let padding = f32::from(self.padding);
+        let padding = self.padding;
         let limits = limits
             .min_width(self.min_width)
             .min_height(self.min_height)
@@ -167,7 +167,10 @@ where
             .pad(padding);
 
         let mut content = self.content.layout(renderer, &limits);
-        content.move_to(Point::new(padding, padding));
+        content.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
 
         let size = limits.resolve(content.size()).pad(padding);
 
@@ -237,6 +240,10 @@ where
         self.width.hash(state);
         self.content.hash_layout(state);
     }
+
+    fn is_focused(&self) -> bool {
+        self.content.is_focused()
+    }
 }
 
 /// The renderer of a [`Button`].
@@ -250,7 +257,7 @@ pub trait Renderer: crate::Renderer + Sized {
     /// The default padding of a [`Button`].
     ///
     /// [`Button`]: struct.Button.html
-    const DEFAULT_PADDING: u16;
+    const DEFAULT_PADDING: Padding;
 
     /// The style supported by this renderer.
     type Style: Default;
@@ -3,7 +3,7 @@ use std::hash::Hash;
 
 use crate::{
     layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
-    Point, Rectangle, Widget,
+    Padding, Point, Rectangle, Size, Widget,
 };
 
 use std::u32;
@@ -13,11 +13,14 @@ use std::u32;
 /// It is normally used for alignment purposes.
 #[allow(missing_debug_implementations)]
 pub struct Container<'a, Message, Renderer: self::Renderer> {
-    padding: u16,
+    padding: Padding,
     width: Length,
     height: Length,
+    min_width: u32,
+    min_height: u32,
     max_width: u32,
     max_height: u32,
+    aspect_ratio: Option<f32>,
     horizontal_alignment: Align,
     vertical_alignment: Align,
     style: Renderer::Style,
@@ -36,11 +39,14 @@ where
         T: Into<Element<'a, Message, Renderer>>,
     {
         Container {
-            padding: 0,
+            padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
+            min_width: 0,
+            min_height: 0,
             max_width: u32::MAX,
             max_height: u32::MAX,
+            aspect_ratio: None,
             horizontal_alignment: Align::Start,
             vertical_alignment: Align::Start,
             style: Renderer::Style::default(),
@@ -51,8 +57,8 @@ where
     /// Sets the padding of the [`Container`].
     ///
     /// [`Container`]: struct.Column.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -72,6 +78,22 @@ where
         self
     }
 
+    /// Sets the minimum width of the [`Container`].
+    ///
+    /// [`Container`]: struct.Container.html
+    pub fn min_width(mut self, min_width: u32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets the minimum height of the [`Container`] in pixels.
+    ///
+    /// [`Container`]: struct.Container.html
+    pub fn min_height(mut self, min_height: u32) -> Self {
+        self.min_height = min_height;
+        self
+    }
+
     /// Sets the maximum width of the [`Container`].
     ///
     /// [`Container`]: struct.Container.html
@@ -88,6 +110,27 @@ where
         self
     }
 
+    /// Sets the aspect ratio of the [`Container`], as its width divided by
+    /// its height.
+    ///
+    /// The ratio applies to the area inside the padding of the
+    /// [`Container`].
+    ///
+    /// # Panics
+    /// Panics if the aspect ratio is not a positive, finite number.
+    ///
+    /// [`Container`]: struct.Container.html
+    pub fn aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        assert!(
+            aspect_ratio > 0.0 && aspect_ratio.is_finite(),
+            "The aspect ratio must be positive and finite, got {}",
+            aspect_ratio
+        );
+
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
     /// Sets the content alignment for the horizontal axis of the [`Container`].
     ///
     /// [`Container`]: struct.Container.html
@@ -147,20 +190,39 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let padding = f32::from(self.padding);
+        let padding = self.padding;
 
         let limits = limits
             .loose()
             .max_width(self.max_width)
             .max_height(self.max_height)
+            .min_width(self.min_width)
+            .min_height(self.min_height)
             .width(self.width)
             .height(self.height)
             .pad(padding);
 
         let mut content = self.content.layout(renderer, &limits.loose());
-        let size = limits.resolve(content.size());
 
-        content.move_to(Point::new(padding, padding));
+        let size = match self.aspect_ratio {
+            Some(aspect_ratio) => {
+                let size =
+                    limits.resolve_aspect_ratio(content.size(), aspect_ratio);
+
+                // The content must fit in the final size
+                content = self
+                    .content
+                    .layout(renderer, &layout::Limits::new(Size::ZERO, size));
+
+                size
+            }
+            None => limits.resolve(content.size()),
+        };
+
+        content.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
         content.align(self.horizontal_alignment, self.vertical_alignment, size);
 
         layout::Node::with_children(size.pad(padding), vec![content])
@@ -211,8 +273,11 @@ where
         self.padding.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.min_width.hash(state);
+        self.min_height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.aspect_ratio.map(f32::to_bits).hash(state);
 
         self.content.hash_layout(state);
     }
@@ -223,6 +288,10 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.content.overlay(layout.children().next().unwrap())
     }
+
+    fn is_focused(&self) -> bool {
+        self.content.is_focused()
+    }
 }
 
 /// The renderer of a [`Container`].
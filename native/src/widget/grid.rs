@@ -0,0 +1,388 @@
+//! Lay out content in rows and columns.
+use crate::layout::grid::{Placement, Tracks};
+use crate::{
+    layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
+    Padding, Point, Widget,
+};
+
+use std::hash::Hash;
+
+/// A container that lays out its contents in rows and columns.
+///
+/// Every child of a [`Grid`] is placed in a [`Cell`], which may span
+/// multiple rows and columns. The size of the rows and columns is defined
+/// by a [`Length`], which makes them shrink to fit their contents, take a
+/// fixed amount of space, or fill a portion of the remaining space.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Align, Length, Text};
+/// #
+/// # pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Null>;
+/// # pub type Cell<'a, Message> = iced_native::grid::Cell<'a, Message, Null>;
+/// # #[derive(Debug, Clone, Copy)]
+/// # enum Message {}
+/// let form: Grid<'_, Message> = Grid::new()
+///     .columns(vec![Length::Shrink, Length::Fill])
+///     .spacing(10)
+///     .push(Cell::new(0, 0, Text::new("Name")).align_x(Align::End))
+///     .push(Cell::new(0, 1, Text::new("Ferris")))
+///     .push(Cell::new(1, 0, Text::new("A long description")).column_span(2));
+/// ```
+///
+/// [`Grid`]: struct.Grid.html
+/// [`Cell`]: struct.Cell.html
+/// [`Length`]: ../../enum.Length.html
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    column_spacing: u16,
+    row_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    placements: Vec<Placement>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn new() -> Self {
+        Grid {
+            column_spacing: 0,
+            row_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            columns: Vec::new(),
+            rows: Vec::new(),
+            placements: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Length`] of every column of the [`Grid`].
+    ///
+    /// Columns without a [`Length`] will shrink to fit their contents.
+    ///
+    /// [`Length`]: ../../enum.Length.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn columns(mut self, columns: Vec<Length>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the [`Length`] of every row of the [`Grid`].
+    ///
+    /// Rows without a [`Length`] will shrink to fit their contents.
+    ///
+    /// [`Length`]: ../../enum.Length.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn rows(mut self, rows: Vec<Length>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the spacing _between_ both rows and columns.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ columns.
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the padding of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn push(mut self, cell: Cell<'a, Message, Renderer>) -> Self {
+        self.placements.push(cell.placement);
+        self.children.push(cell.content);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        layout::grid::resolve(
+            renderer,
+            &limits,
+            self.padding,
+            Tracks {
+                lengths: &self.columns,
+                spacing: f32::from(self.column_spacing),
+            },
+            Tracks {
+                lengths: &self.rows,
+                spacing: f32::from(self.row_spacing),
+            },
+            &self.placements,
+            &self.children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.children.iter_mut().zip(layout.children()).for_each(
+            |(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            },
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            &self.children,
+            layout,
+            cursor_position,
+            draw_at,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.padding.hash(state);
+        self.column_spacing.hash(state);
+        self.row_spacing.hash(state);
+        self.columns.hash(state);
+        self.rows.hash(state);
+        self.placements.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn is_focused(&self) -> bool {
+        self.children.iter().any(Element::is_focused)
+    }
+}
+
+/// A cell of a [`Grid`], holding some content.
+///
+/// [`Grid`]: struct.Grid.html
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message, Renderer> {
+    placement: Placement,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Cell<'a, Message, Renderer> {
+    /// Creates a new [`Cell`] with the given content in the given row and
+    /// column.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn new<E>(row: usize, column: usize, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Cell {
+            placement: Placement {
+                row,
+                column,
+                row_span: 1,
+                column_span: 1,
+                align_x: Align::Start,
+                align_y: Align::Start,
+            },
+            content: content.into(),
+        }
+    }
+
+    /// Sets the amount of rows spanned by the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn row_span(mut self, rows: usize) -> Self {
+        self.placement.row_span = rows;
+        self
+    }
+
+    /// Sets the amount of columns spanned by the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn column_span(mut self, columns: usize) -> Self {
+        self.placement.column_span = columns;
+        self
+    }
+
+    /// Sets the horizontal alignment of the content of the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn align_x(mut self, align: Align) -> Self {
+        self.placement.align_x = align;
+        self
+    }
+
+    /// Sets the vertical alignment of the content of the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn align_y(mut self, align: Align) -> Self {
+        self.placement.align_y = align;
+        self
+    }
+}
+
+/// The renderer of a [`Grid`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Grid`] in your user interface.
+///
+/// [`Grid`]: struct.Grid.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Grid`].
+    ///
+    /// It receives:
+    /// - the children of the [`Grid`]
+    /// - the [`Layout`] of the [`Grid`] and its children
+    /// - the cursor position
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
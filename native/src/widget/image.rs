@@ -18,11 +18,12 @@ use std::{
 /// ```
 ///
 /// <img src="https://github.com/hecrj/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
-#[derive(Debug, Hash)]
+#[derive(Debug)]
 pub struct Image {
     handle: Handle,
     width: Length,
     height: Length,
+    aspect_ratio: Option<f32>,
 }
 
 impl Image {
@@ -34,6 +35,7 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            aspect_ratio: None,
         }
     }
 
@@ -52,6 +54,37 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the aspect ratio of the [`Image`], as its width divided by its
+    /// height.
+    ///
+    /// By default, the aspect ratio of the image data is kept. Setting it
+    /// explicitly can be useful to reserve the right amount of space before
+    /// the image is loaded.
+    ///
+    /// # Panics
+    /// Panics if the aspect ratio is not a positive, finite number.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        assert!(
+            aspect_ratio > 0.0 && aspect_ratio.is_finite(),
+            "The aspect ratio must be positive and finite, got {}",
+            aspect_ratio
+        );
+
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+}
+
+impl Hash for Image {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.handle.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.aspect_ratio.map(f32::to_bits).hash(state);
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -73,7 +106,8 @@ where
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
 
-        let aspect_ratio = width as f32 / height as f32;
+        let aspect_ratio =
+            self.aspect_ratio.unwrap_or(width as f32 / height as f32);
 
         let mut size = limits
             .width(self.width)
@@ -83,9 +117,9 @@ where
         let viewport_aspect_ratio = size.width / size.height;
 
         if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
+            size.width = size.height * aspect_ratio;
         } else {
-            size.height = height as f32 * size.width / width as f32;
+            size.height = size.width / aspect_ratio;
         }
 
         layout::Node::new(size)
@@ -109,6 +143,7 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.aspect_ratio.map(f32::to_bits).hash(state);
     }
 }
 
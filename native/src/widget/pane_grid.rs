@@ -536,29 +536,22 @@ where
                 }
                 _ => {}
             },
-            Event::Keyboard(keyboard_event) => {
-                match keyboard_event {
-                    keyboard::Event::KeyPressed {
-                        modifiers,
-                        key_code,
-                    } => {
-                        if let Some(on_key_press) = &self.on_key_press {
-                            // TODO: Discard when event is captured
-                            if let Some(_) = self.state.active_pane() {
-                                if modifiers.matches(self.modifier_keys) {
-                                    if let Some(message) =
-                                        on_key_press(KeyPressEvent {
-                                            key_code,
-                                            modifiers,
-                                        })
-                                    {
-                                        messages.push(message);
-                                    }
-                                }
-                            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                modifiers,
+                key_code,
+            }) => {
+                if let Some(on_key_press) = &self.on_key_press {
+                    // TODO: Discard when event is captured
+                    if self.state.active_pane().is_some()
+                        && modifiers.matches(self.modifier_keys)
+                    {
+                        if let Some(message) = on_key_press(KeyPressEvent {
+                            key_code,
+                            modifiers,
+                        }) {
+                            messages.push(message);
                         }
                     }
-                    _ => {}
                 }
             }
             _ => {}
@@ -653,6 +646,10 @@ where
             .filter_map(|((_, pane), layout)| pane.overlay(layout))
             .next()
     }
+
+    fn is_focused(&self) -> bool {
+        self.elements.iter().any(|(_, pane)| pane.is_focused())
+    }
 }
 
 /// The renderer of a [`PaneGrid`].
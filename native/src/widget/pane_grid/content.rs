@@ -210,6 +210,11 @@ where
 
         self.body.overlay(body_layout)
     }
+
+    pub(crate) fn is_focused(&self) -> bool {
+        self.title_bar.iter().any(TitleBar::is_focused)
+            || self.body.is_focused()
+    }
 }
 
 impl<'a, T, Message, Renderer> From<T> for Content<'a, Message, Renderer>
@@ -1,7 +1,7 @@
 use crate::layout;
 use crate::pane_grid;
 use crate::{
-    Clipboard, Element, Event, Hasher, Layout, Point, Rectangle, Size,
+    Clipboard, Element, Event, Hasher, Layout, Padding, Point, Rectangle, Size,
 };
 
 /// The title bar of a [`Pane`].
@@ -12,7 +12,7 @@ pub struct TitleBar<'a, Message, Renderer: pane_grid::Renderer> {
     title: String,
     title_size: Option<u16>,
     controls: Option<Element<'a, Message, Renderer>>,
-    padding: u16,
+    padding: Padding,
     always_show_controls: bool,
     style: Renderer::Style,
 }
@@ -29,7 +29,7 @@ where
             title: title.into(),
             title_size: None,
             controls: None,
-            padding: 0,
+            padding: Padding::ZERO,
             always_show_controls: false,
             style: Renderer::Style::default(),
         }
@@ -57,8 +57,8 @@ where
     /// Sets the padding of the [`TitleBar`].
     ///
     /// [`TitleBar`]: struct.TitleBar.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -194,7 +194,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let padding = f32::from(self.padding);
+        let padding = self.padding;
         let limits = limits.pad(padding);
         let max_size = limits.max();
 
@@ -235,7 +235,10 @@ where
             layout::Node::new(Size::new(max_size.width, title_height))
         };
 
-        node.move_to(Point::new(padding, padding));
+        node.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
 
         layout::Node::with_children(node.size().pad(padding), vec![node])
     }
@@ -267,4 +270,8 @@ where
             );
         }
     }
+
+    pub(crate) fn is_focused(&self) -> bool {
+        self.controls.iter().any(Element::is_focused)
+    }
 }
@@ -1,10 +1,18 @@
 //! Provide progress feedback to your users.
+use crate::animation;
+use crate::renderer::request_redraw;
 use crate::{
     layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::{hash::Hash, ops::RangeInclusive};
 
+/// The time an indeterminate [`ProgressBar`] takes to complete a cycle.
+///
+/// [`ProgressBar`]: struct.ProgressBar.html
+const INDETERMINATE_PERIOD: Duration = Duration::from_millis(1500);
+
 /// A bar that displays progress.
 ///
 /// # Example
@@ -15,6 +23,9 @@ use std::{hash::Hash, ops::RangeInclusive};
 /// let value = 50.0;
 ///
 /// ProgressBar::new(0.0..=100.0, value);
+///
+/// // When the total amount of work is unknown
+/// ProgressBar::indeterminate();
 /// ```
 ///
 /// ![Progress bar drawn with `iced_wgpu`](https://user-images.githubusercontent.com/18618951/71662391-a316c200-2d51-11ea-9cef-52758cab85e3.png)
@@ -22,6 +33,7 @@ use std::{hash::Hash, ops::RangeInclusive};
 pub struct ProgressBar<Renderer: self::Renderer> {
     range: RangeInclusive<f32>,
     value: f32,
+    is_indeterminate: bool,
     width: Length,
     height: Option<Length>,
     style: Renderer::Style,
@@ -39,12 +51,26 @@ impl<Renderer: self::Renderer> ProgressBar<Renderer> {
         ProgressBar {
             value: value.max(*range.start()).min(*range.end()),
             range,
+            is_indeterminate: false,
             width: Length::Fill,
             height: None,
             style: Renderer::Style::default(),
         }
     }
 
+    /// Creates a new indeterminate [`ProgressBar`].
+    ///
+    /// An indeterminate [`ProgressBar`] displays an endless animation to
+    /// indicate that some work is being done when its progress is unknown.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn indeterminate() -> Self {
+        ProgressBar {
+            is_indeterminate: true,
+            ..ProgressBar::new(0.0..=1.0, 0.0)
+        }
+    }
+
     /// Sets the width of the [`ProgressBar`].
     ///
     /// [`ProgressBar`]: struct.ProgressBar.html
@@ -104,14 +130,24 @@ where
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
         _cursor_position: Point,
-        _draw_at: &mut Option<std::time::Instant>,
+        draw_at: &mut Option<std::time::Instant>,
     ) -> Renderer::Output {
-        renderer.draw(
-            layout.bounds(),
-            self.range.clone(),
-            self.value,
-            &self.style,
-        )
+        if self.is_indeterminate {
+            request_redraw(draw_at, Some(Instant::now() + animation::FRAME));
+
+            renderer.draw_indeterminate(
+                layout.bounds(),
+                animation::cycle(INDETERMINATE_PERIOD),
+                &self.style,
+            )
+        } else {
+            renderer.draw(
+                layout.bounds(),
+                self.range.clone(),
+                self.value,
+                &self.style,
+            )
+        }
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -156,6 +192,20 @@ pub trait Renderer: crate::Renderer {
         value: f32,
         style: &Self::Style,
     ) -> Self::Output;
+
+    /// Draws an indeterminate [`ProgressBar`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`ProgressBar`]
+    ///   * the progress of the current animation cycle, from `0.0` to `1.0`
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    fn draw_indeterminate(
+        &self,
+        bounds: Rectangle,
+        cycle: f32,
+        style: &Self::Style,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<ProgressBar<Renderer>>
@@ -0,0 +1,251 @@
+//! Build content from the space available to it.
+use crate::{
+    layout, overlay, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Size, Widget,
+};
+
+use std::cell::{Cell, RefCell, RefMut};
+use std::hash::Hash;
+
+/// A widget that builds its content from the size available to it.
+///
+/// The content of a [`Responsive`] widget is produced by a closure that is
+/// invoked during layout with the maximum size the widget can take. This
+/// allows switching between different layouts depending on the size of the
+/// window, for instance.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, responsive, Column, Element, Row, Text};
+/// #
+/// # pub type Responsive<'a, Message> =
+/// #     iced_native::Responsive<'a, Message, Null>;
+/// # #[derive(Debug, Clone, Copy)]
+/// # enum Message {}
+/// let mut state = responsive::State::new();
+///
+/// let responsive: Responsive<'_, Message> =
+///     Responsive::new(&mut state, |size| {
+///         if size.width > 600.0 {
+///             Row::new().push(Text::new("Sidebar")).into()
+///         } else {
+///             Column::new().push(Text::new("Stacked")).into()
+///         }
+///     });
+/// ```
+///
+/// [`Responsive`]: struct.Responsive.html
+#[allow(missing_debug_implementations)]
+pub struct Responsive<'a, Message, Renderer> {
+    state: &'a mut State,
+    width: Length,
+    height: Length,
+    view: Box<dyn Fn(Size) -> Element<'a, Message, Renderer> + 'a>,
+    content: RefCell<Option<(Size, Element<'a, Message, Renderer>)>>,
+}
+
+impl<'a, Message, Renderer> Responsive<'a, Message, Renderer> {
+    /// Creates a new [`Responsive`] widget with the given [`State`] and a
+    /// closure that produces its content given the available size.
+    ///
+    /// By default, it fills all the available space.
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(state: &'a mut State, view: F) -> Self
+    where
+        F: 'a + Fn(Size) -> Element<'a, Message, Renderer>,
+    {
+        Responsive {
+            state,
+            width: Length::Fill,
+            height: Length::Fill,
+            view: Box::new(view),
+            content: RefCell::new(None),
+        }
+    }
+
+    /// Sets the width of the [`Responsive`] widget.
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Responsive`] widget.
+    ///
+    /// [`Responsive`]: struct.Responsive.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    fn size(&self, layout: Layout<'_>) -> Size {
+        self.state
+            .available_size
+            .get()
+            .unwrap_or_else(|| layout.bounds().size())
+    }
+
+    fn content(
+        &self,
+        size: Size,
+    ) -> RefMut<'_, Element<'a, Message, Renderer>> {
+        let mut content = self.content.borrow_mut();
+
+        if content.as_ref().map(|(built_for, _)| *built_for) != Some(size) {
+            *content = Some((size, (self.view)(size)));
+        }
+
+        RefMut::map(content, |content| {
+            &mut content.as_mut().expect("Build responsive content").1
+        })
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Responsive<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.max();
+
+        self.state.available_size.set(Some(size));
+
+        let content = self.content(size).layout(renderer, &limits);
+
+        layout::Node::with_children(
+            limits.resolve(content.size()),
+            vec![content],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let size = self.size(layout);
+
+        if let Some(content_layout) = layout.children().next() {
+            self.content(size).widget.on_event(
+                event,
+                content_layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Renderer::Output {
+        let content_layout = layout
+            .children()
+            .next()
+            .expect("Responsive layout has content");
+
+        self.content(self.size(layout)).draw(
+            renderer,
+            defaults,
+            content_layout,
+            cursor_position,
+            draw_at,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+
+        // The content depends on the size available during the last layout.
+        // If the layout is reused, this size has not changed.
+        if let Some(size) = self.state.available_size.get() {
+            self.content(size).widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let size = self.size(layout);
+        let content_layout = layout.children().next()?;
+
+        // Make sure the content is built for the current size
+        drop(self.content(size));
+
+        let (_, content) = self.content.get_mut().as_mut()?;
+
+        content.widget.overlay(content_layout)
+    }
+
+    fn is_focused(&self) -> bool {
+        self.content
+            .borrow()
+            .iter()
+            .any(|(_, content)| content.is_focused())
+    }
+}
+
+/// The local state of a [`Responsive`] widget.
+///
+/// It remembers the size available to the widget, so its content can be
+/// rebuilt when the layout is cached.
+///
+/// [`Responsive`]: struct.Responsive.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    available_size: Cell<Option<Size>>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, Message, Renderer> From<Responsive<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(
+        responsive: Responsive<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(responsive)
+    }
+}
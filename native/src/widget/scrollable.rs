@@ -1,7 +1,7 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::{
     column, layout, mouse, overlay, Align, Clipboard, Column, Element, Event,
-    Hasher, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    Hasher, Layout, Length, Padding, Point, Rectangle, Size, Vector, Widget,
 };
 
 use std::{f32, hash::Hash, u32};
@@ -45,8 +45,8 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
     /// Sets the padding of the [`Scrollable`].
     ///
     /// [`Scrollable`]: struct.Scrollable.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.content = self.content.padding(units);
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.content = self.content.padding(padding);
         self
     }
 
@@ -335,6 +335,10 @@ where
                 overlay.translate(Vector::new(0.0, -(offset as f32)))
             })
     }
+
+    fn is_focused(&self) -> bool {
+        self.content.is_focused()
+    }
 }
 
 /// The local state of a [`Scrollable`].
@@ -0,0 +1,150 @@
+//! Show an endless animation while some work is being done.
+use crate::animation;
+use crate::renderer::request_redraw;
+use crate::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// The time a [`Spinner`] takes to complete a cycle.
+///
+/// [`Spinner`]: struct.Spinner.html
+const PERIOD: Duration = Duration::from_millis(1200);
+
+/// A circular spinner that indicates some work of unknown length is being
+/// done.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Spinner = iced_native::Spinner<Null>;
+/// Spinner::new().size(30);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Spinner<Renderer: self::Renderer> {
+    size: u16,
+    style: Renderer::Style,
+}
+
+impl<Renderer: self::Renderer> Spinner<Renderer> {
+    /// Creates a new [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn new() -> Self {
+        Spinner {
+            size: Renderer::DEFAULT_SIZE,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the diameter of the [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the style of the [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Renderer: self::Renderer> Default for Spinner<Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Spinner<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = f32::from(self.size);
+
+        layout::Node::new(limits.resolve(Size::new(size, size)))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Renderer::Output {
+        request_redraw(draw_at, Some(Instant::now() + animation::FRAME));
+
+        renderer.draw(layout.bounds(), animation::cycle(PERIOD), &self.style)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`Spinner`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Spinner`] in your user interface.
+///
+/// [`Spinner`]: struct.Spinner.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default diameter of a [`Spinner`].
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    const DEFAULT_SIZE: u16;
+
+    /// Draws a [`Spinner`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Spinner`]
+    ///   * the progress of the current animation cycle, from `0.0` to `1.0`
+    ///   * the style of the [`Spinner`]
+    ///
+    /// [`Spinner`]: struct.Spinner.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        cycle: f32,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Spinner<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(spinner: Spinner<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(spinner)
+    }
+}
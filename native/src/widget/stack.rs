@@ -0,0 +1,372 @@
+//! Display content on top of other content.
+use crate::{
+    layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+
+/// A container that displays its contents on top of each other.
+///
+/// Every child of a [`Stack`] is a [`Layer`], which is aligned inside the
+/// bounds of the [`Stack`] and may be offset from there. Layers are drawn in
+/// order, which means the last one is displayed on top.
+///
+/// Only the topmost [`Layer`] under the mouse cursor can interact with it.
+/// Layers below do not see the cursor.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Align, Column, Length, Vector};
+/// #
+/// # pub type Stack<'a, Message> = iced_native::Stack<'a, Message, Null>;
+/// # pub type Layer<'a, Message> = iced_native::stack::Layer<'a, Message, Null>;
+/// # #[derive(Debug, Clone, Copy)]
+/// # enum Message {}
+/// let icon = Column::new().width(Length::Units(48)).height(Length::Units(48));
+/// let badge = Column::new().width(Length::Units(16)).height(Length::Units(16));
+///
+/// let stack: Stack<'_, Message> = Stack::new().push(icon).push_layer(
+///     Layer::new(badge)
+///         .align_x(Align::End)
+///         .offset(Vector::new(4.0, -4.0)),
+/// );
+/// ```
+///
+/// [`Stack`]: struct.Stack.html
+/// [`Layer`]: struct.Layer.html
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message, Renderer> {
+    width: Length,
+    height: Length,
+    placements: Vec<Placement>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates an empty [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn new() -> Self {
+        Stack {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            placements: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Adds an [`Element`] on top of the [`Stack`], aligned to its top left
+    /// corner.
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Stack`]: struct.Stack.html
+    pub fn push<E>(self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_layer(Layer::new(child))
+    }
+
+    /// Adds a [`Layer`] on top of the [`Stack`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    /// [`Stack`]: struct.Stack.html
+    pub fn push_layer(mut self, layer: Layer<'a, Message, Renderer>) -> Self {
+        self.placements.push(layer.placement);
+        self.children.push(layer.content);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Stack<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let child_limits = layout::Limits::new(Size::ZERO, limits.max());
+
+        let mut nodes: Vec<layout::Node> = self
+            .children
+            .iter()
+            .map(|child| child.layout(renderer, &child_limits))
+            .collect();
+
+        let intrinsic_size = nodes.iter().fold(Size::ZERO, |size, node| {
+            let node_size = node.size();
+
+            Size::new(
+                size.width.max(node_size.width),
+                size.height.max(node_size.height),
+            )
+        });
+
+        let size = limits.resolve(intrinsic_size);
+
+        for (node, placement) in nodes.iter_mut().zip(&self.placements) {
+            node.move_to(Point::new(placement.offset.x, placement.offset.y));
+            node.align(placement.align_x, placement.align_y, size);
+        }
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let topmost = topmost(layout, cursor_position);
+        let layouts: Vec<_> = layout.children().collect();
+
+        // Layers receive events from top to bottom
+        self.children
+            .iter_mut()
+            .zip(layouts)
+            .enumerate()
+            .rev()
+            .for_each(|(i, (child, layout))| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    layer_cursor_position(i, topmost, cursor_position),
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            });
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            &self.children,
+            layout,
+            cursor_position,
+            draw_at,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+
+        for (child, placement) in self.children.iter().zip(&self.placements) {
+            placement.align_x.hash(state);
+            placement.align_y.hash(state);
+            placement.offset.x.to_bits().hash(state);
+            placement.offset.y.to_bits().hash(state);
+
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let layouts: Vec<_> = layout.children().collect();
+
+        self.children
+            .iter_mut()
+            .zip(layouts)
+            .rev()
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn is_focused(&self) -> bool {
+        self.children.iter().any(Element::is_focused)
+    }
+}
+
+/// A layer of a [`Stack`], holding some content.
+///
+/// [`Stack`]: struct.Stack.html
+#[allow(missing_debug_implementations)]
+pub struct Layer<'a, Message, Renderer> {
+    placement: Placement,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Layer<'a, Message, Renderer> {
+    /// Creates a new [`Layer`] with the given content, aligned to the top
+    /// left corner of its [`Stack`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    /// [`Stack`]: struct.Stack.html
+    pub fn new<E>(content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Layer {
+            placement: Placement {
+                align_x: Align::Start,
+                align_y: Align::Start,
+                offset: Vector::new(0.0, 0.0),
+            },
+            content: content.into(),
+        }
+    }
+
+    /// Sets the horizontal alignment of the [`Layer`] in its [`Stack`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    /// [`Stack`]: struct.Stack.html
+    pub fn align_x(mut self, align: Align) -> Self {
+        self.placement.align_x = align;
+        self
+    }
+
+    /// Sets the vertical alignment of the [`Layer`] in its [`Stack`].
+    ///
+    /// [`Layer`]: struct.Layer.html
+    /// [`Stack`]: struct.Stack.html
+    pub fn align_y(mut self, align: Align) -> Self {
+        self.placement.align_y = align;
+        self
+    }
+
+    /// Sets the offset of the [`Layer`] from its aligned position.
+    ///
+    /// [`Layer`]: struct.Layer.html
+    pub fn offset(mut self, offset: Vector) -> Self {
+        self.placement.offset = offset;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Placement {
+    align_x: Align,
+    align_y: Align,
+    offset: Vector,
+}
+
+/// Returns the index of the topmost layer of a [`Stack`] under the given
+/// cursor position, if any.
+///
+/// Renderers can use it to hide the cursor from the layers below.
+///
+/// [`Stack`]: struct.Stack.html
+pub fn topmost(layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+    layout
+        .children()
+        .enumerate()
+        .filter(|(_, layout)| layout.bounds().contains(cursor_position))
+        .map(|(i, _)| i)
+        .last()
+}
+
+/// Returns the cursor position seen by the layer with the given index of a
+/// [`Stack`], given its [`topmost`] layer under the cursor.
+///
+/// Layers below the topmost one see the cursor outside of the window.
+///
+/// [`Stack`]: struct.Stack.html
+/// [`topmost`]: fn.topmost.html
+pub fn layer_cursor_position(
+    index: usize,
+    topmost: Option<usize>,
+    cursor_position: Point,
+) -> Point {
+    match topmost {
+        Some(topmost) if index < topmost => Point::new(-1.0, -1.0),
+        _ => cursor_position,
+    }
+}
+
+/// The renderer of a [`Stack`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Stack`] in your user interface.
+///
+/// [`Stack`]: struct.Stack.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Stack`].
+    ///
+    /// It receives:
+    /// - the children of the [`Stack`], from bottom to top
+    /// - the [`Layout`] of the [`Stack`] and its children
+    /// - the cursor position
+    ///
+    /// Every child must be displayed on top of the previous ones. Use
+    /// [`layer_cursor_position`] to obtain the cursor position of each
+    /// child.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    /// [`layer_cursor_position`]: fn.layer_cursor_position.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        stack: Stack<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stack)
+    }
+}
@@ -17,8 +17,8 @@ use editor::Editor;
 use crate::{
     keyboard, layout,
     mouse::{self, click},
-    text, Clipboard, Element, Event, Hasher, Layout, Length, Point, Rectangle,
-    Size, Widget,
+    text, Clipboard, Element, Event, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Size, Widget,
 };
 
 use std::u32;
@@ -56,7 +56,7 @@ pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     font: Renderer::Font,
     width: Length,
     max_width: u32,
-    padding: u16,
+    padding: Padding,
     size: Option<u16>,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
@@ -91,7 +91,7 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
             font: Default::default(),
             width: Length::Fill,
             max_width: u32::MAX,
-            padding: 0,
+            padding: Padding::ZERO,
             size: None,
             on_change: Box::new(on_change),
             on_submit: None,
@@ -134,8 +134,8 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
     /// Sets the padding of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -191,7 +191,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let padding = self.padding as f32;
+        let padding = self.padding;
         let text_size = self.size.unwrap_or(renderer.default_size());
 
         let limits = limits
@@ -201,7 +201,10 @@ where
             .height(Length::Units(text_size));
 
         let mut text = layout::Node::new(limits.resolve(Size::ZERO));
-        text.move_to(Point::new(padding, padding));
+        text.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
 
         layout::Node::with_children(text.size().pad(padding), vec![text])
     }
@@ -280,6 +283,10 @@ where
 
                 self.state.is_dragging = is_clicked;
                 self.state.is_focused = is_clicked;
+
+                if !is_clicked {
+                    self.state.preedit = None;
+                }
                 if is_clicked {
                     self.state.cursor.on_click();
                 };
@@ -328,6 +335,35 @@ where
                 let message = (self.on_change)(editor.contents());
                 messages.push(message);
             }
+            Event::Keyboard(keyboard::Event::ImePreedit(text))
+                if self.state.is_focused =>
+            {
+                // The composed text is only drawn, so it can be masked as
+                // soon as it arrives
+                self.state.preedit = if text.is_empty() {
+                    None
+                } else if self.is_secure {
+                    Some(Value::new(&text).secure().to_string())
+                } else {
+                    Some(text)
+                };
+            }
+            Event::Keyboard(keyboard::Event::ImeCommit(text))
+                if self.state.is_focused =>
+            {
+                self.state.preedit = None;
+
+                let content: String =
+                    text.chars().filter(|c| !c.is_control()).collect();
+
+                let mut editor =
+                    Editor::new(&mut self.value, &mut self.state.cursor);
+
+                editor.paste(Value::new(&content));
+
+                let message = (self.on_change)(editor.contents());
+                messages.push(message);
+            }
             Event::Keyboard(keyboard::Event::KeyPressed {
                 key_code,
                 modifiers,
@@ -475,6 +511,7 @@ where
                     self.state.is_focused = false;
                     self.state.is_dragging = false;
                     self.state.is_pasting = None;
+                    self.state.preedit = None;
                 }
                 _ => {}
             },
@@ -542,6 +579,10 @@ where
         self.padding.hash(state);
         self.size.hash(state);
     }
+
+    fn is_focused(&self) -> bool {
+        self.state.is_focused()
+    }
 }
 
 /// The renderer of a [`TextInput`].
@@ -656,6 +697,7 @@ pub struct State {
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    preedit: Option<String>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -677,6 +719,7 @@ impl State {
             is_pasting: None,
             last_click: None,
             cursor: Cursor::default(),
+            preedit: None,
         }
     }
 
@@ -695,6 +738,17 @@ impl State {
         self.cursor
     }
 
+    /// Returns the text that an input method is composing in the
+    /// [`TextInput`], if any.
+    ///
+    /// It is not part of the value of the [`TextInput`] until committed. It
+    /// is masked if the [`TextInput`] is secure.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn preedit(&self) -> Option<&str> {
+        self.preedit.as_deref()
+    }
+
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
     ///
     /// [`Cursor`]: struct.Cursor.html
@@ -799,3 +853,108 @@ mod platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    fn ime_events(
+        state: &mut State,
+        value: &str,
+        is_secure: bool,
+        events: Vec<keyboard::Event>,
+    ) -> Vec<String> {
+        let renderer = Null::new();
+        let node = layout::Node::new(Size::new(100.0, 20.0));
+        let mut messages = Vec::new();
+
+        let mut text_input =
+            TextInput::<String, Null>::new(state, "", value, |value| value);
+
+        if is_secure {
+            text_input = text_input.password();
+        }
+
+        for event in events {
+            text_input.on_event(
+                Event::Keyboard(event),
+                Layout::new(&node),
+                Point::ORIGIN,
+                &mut messages,
+                &renderer,
+                None,
+            );
+        }
+
+        messages
+    }
+
+    fn preedit(text: &str) -> keyboard::Event {
+        keyboard::Event::ImePreedit(String::from(text))
+    }
+
+    fn commit(text: &str) -> keyboard::Event {
+        keyboard::Event::ImeCommit(String::from(text))
+    }
+
+    #[test]
+    fn preedit_is_shown_until_committed() {
+        let mut state = State::focused();
+
+        let messages =
+            ime_events(&mut state, "", false, vec![preedit("nihon")]);
+
+        assert!(messages.is_empty());
+        assert_eq!(state.preedit(), Some("nihon"));
+
+        let messages = ime_events(&mut state, "", false, vec![commit("日本")]);
+
+        assert_eq!(messages, vec![String::from("日本")]);
+        assert_eq!(state.preedit(), None);
+    }
+
+    #[test]
+    fn empty_preedit_is_cleared() {
+        let mut state = State::focused();
+
+        let _ =
+            ime_events(&mut state, "", false, vec![preedit("ni"), preedit("")]);
+
+        assert_eq!(state.preedit(), None);
+    }
+
+    #[test]
+    fn commit_is_inserted_at_the_cursor() {
+        let mut state = State::focused();
+        state.move_cursor_to(1);
+
+        let messages = ime_events(&mut state, "ab", false, vec![commit("x\n")]);
+
+        assert_eq!(messages, vec![String::from("axb")]);
+    }
+
+    #[test]
+    fn unfocused_input_ignores_the_input_method() {
+        let mut state = State::new();
+
+        let messages = ime_events(
+            &mut state,
+            "",
+            false,
+            vec![preedit("ni"), commit("日本")],
+        );
+
+        assert!(messages.is_empty());
+        assert_eq!(state.preedit(), None);
+    }
+
+    #[test]
+    fn secure_input_masks_the_preedit() {
+        let mut state = State::focused();
+
+        let _ = ime_events(&mut state, "", true, vec![preedit("abc")]);
+
+        assert_eq!(state.preedit(), Some("•••"));
+    }
+}
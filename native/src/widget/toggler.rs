@@ -0,0 +1,401 @@
+//! Show toggle controls using togglers.
+//!
+//! A [`Toggler`] has some local [`State`].
+//!
+//! [`Toggler`]: struct.Toggler.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+use crate::animation::{Animated, Tween};
+use crate::renderer::request_redraw;
+use crate::{
+    layout, mouse, row, text, Align, Clipboard, Element, Event, Hasher,
+    HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
+    VerticalAlignment, Widget,
+};
+
+/// A toggler widget, an on/off switch with a label.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::toggler;
+/// #
+/// # type Toggler<'a, Message> =
+/// #     iced_native::Toggler<'a, Message, iced_native::renderer::Null>;
+/// #
+/// pub enum Message {
+///     TogglerToggled(bool),
+/// }
+///
+/// let mut state = toggler::State::new();
+/// let is_active = true;
+///
+/// Toggler::new(&mut state, is_active, "Toggle me!", Message::TogglerToggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Toggler<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    state: &'a mut State,
+    is_active: bool,
+    on_toggle: Box<dyn Fn(bool) -> Message>,
+    label: String,
+    label_position: LabelPosition,
+    width: Length,
+    size: u16,
+    spacing: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer + text::Renderer>
+    Toggler<'a, Message, Renderer>
+{
+    /// Creates a new [`Toggler`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Toggler`]
+    ///   * a boolean describing whether the [`Toggler`] is active or not
+    ///   * the label of the [`Toggler`]
+    ///   * a function that will be called when the [`Toggler`] is toggled. It
+    ///     will receive the new state of the [`Toggler`] and must produce a
+    ///     `Message`.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        is_active: bool,
+        label: impl Into<String>,
+        f: F,
+    ) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        Toggler {
+            state,
+            is_active,
+            on_toggle: Box::new(f),
+            label: label.into(),
+            label_position: LabelPosition::Right,
+            width: Length::Shrink,
+            size: <Renderer as self::Renderer>::DEFAULT_SIZE,
+            spacing: Renderer::DEFAULT_SPACING,
+            text_size: None,
+            font: Renderer::Font::default(),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`LabelPosition`] of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`LabelPosition`]: enum.LabelPosition.html
+    pub fn label_position(mut self, label_position: LabelPosition) -> Self {
+        self.label_position = label_position;
+        self
+    }
+
+    /// Sets the size of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the width of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the [`Toggler`] and the text.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the text size of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the [`Font`] of the text of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The position of the label of a [`Toggler`].
+///
+/// [`Toggler`]: struct.Toggler.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LabelPosition {
+    /// The label is placed to the left of the switch.
+    Left,
+
+    /// The label is placed to the right of the switch.
+    Right,
+}
+
+/// The local state of a [`Toggler`].
+///
+/// [`Toggler`]: struct.Toggler.html
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    knob: Animated<f32>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the position of the knob, from `0.0` (inactive) to `1.0`
+    /// (active), along with the next moment the knob needs to be redrawn, if
+    /// it is still moving.
+    fn knob_position(&self, is_active: bool) -> (f32, Option<Instant>) {
+        let target = knob_target(is_active);
+
+        // The knob only moves if the toggle was acknowledged by the
+        // application.
+        if (self.knob.target() - target).abs() < f32::EPSILON {
+            (self.knob.value(), self.knob.next_draw())
+        } else {
+            (target, None)
+        }
+    }
+
+    fn toggle(&mut self, is_active: bool) {
+        let current = knob_target(is_active);
+
+        if (self.knob.target() - current).abs() >= f32::EPSILON {
+            self.knob.snap(current);
+        }
+
+        self.knob.set(knob_target(!is_active));
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State {
+            knob: Animated::new(0.0, Tween::new(Duration::from_millis(150))),
+        }
+    }
+}
+
+fn knob_target(is_active: bool) -> f32 {
+    if is_active {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Toggler<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer + row::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let switch = Row::new()
+            .width(Length::Units(2 * self.size))
+            .height(Length::Units(self.size));
+
+        let label = Text::new(&self.label)
+            .font(self.font)
+            .width(self.width)
+            .size(self.text_size.unwrap_or(renderer.default_size()));
+
+        let row = Row::<(), Renderer>::new()
+            .width(self.width)
+            .spacing(self.spacing)
+            .align_items(Align::Center);
+
+        match self.label_position {
+            LabelPosition::Left => row.push(label).push(switch),
+            LabelPosition::Right => row.push(switch).push(label),
+        }
+        .layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            let mouse_over = layout.bounds().contains(cursor_position);
+
+            if mouse_over {
+                self.state.toggle(self.is_active);
+
+                messages.push((self.on_toggle)(!self.is_active));
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+
+        let (switch_layout, label_layout) = match self.label_position {
+            LabelPosition::Left => {
+                let label_layout = children.next().unwrap();
+
+                (children.next().unwrap(), label_layout)
+            }
+            LabelPosition::Right => {
+                let switch_layout = children.next().unwrap();
+
+                (switch_layout, children.next().unwrap())
+            }
+        };
+
+        let label = text::Renderer::draw(
+            renderer,
+            defaults,
+            label_layout.bounds(),
+            &self.label,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            None,
+            HorizontalAlignment::Left,
+            VerticalAlignment::Center,
+        );
+
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let (knob_position, next_draw) =
+            self.state.knob_position(self.is_active);
+
+        request_redraw(draw_at, next_draw);
+
+        self::Renderer::draw(
+            renderer,
+            switch_layout.bounds(),
+            self.is_active,
+            knob_position,
+            is_mouse_over,
+            label,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.label.hash(state);
+        self.label_position.hash(state);
+        self.width.hash(state);
+        self.size.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
+    }
+}
+
+/// The renderer of a [`Toggler`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Toggler`] in your user interface.
+///
+/// [`Toggler`]: struct.Toggler.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default size of a [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    const DEFAULT_SIZE: u16;
+
+    /// The default spacing of a [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    const DEFAULT_SPACING: u16;
+
+    /// Draws a [`Toggler`].
+    ///
+    /// It receives:
+    ///   * the bounds of the switch of the [`Toggler`]
+    ///   * whether the [`Toggler`] is active or not
+    ///   * the position of the knob, from `0.0` (inactive) to `1.0` (active)
+    ///   * whether the mouse is over the [`Toggler`] or not
+    ///   * the drawn label of the [`Toggler`]
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        is_active: bool,
+        knob_position: f32,
+        is_mouse_over: bool,
+        label: Self::Output,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Toggler<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer + text::Renderer + row::Renderer,
+    Message: 'a,
+{
+    fn from(
+        toggler: Toggler<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(toggler)
+    }
+}
@@ -0,0 +1,307 @@
+//! Distribute content in lines that wrap when running out of space.
+use crate::layout::{flex::Axis, wrap::Spacing};
+use crate::{
+    layout, overlay, Align, Clipboard, Element, Event, Hasher, Layout, Length,
+    Padding, Point, Widget,
+};
+
+use std::hash::Hash;
+
+/// A container that distributes its contents in lines, wrapping them onto a
+/// new line when running out of space.
+///
+/// By default, a [`Wrap`] places its contents from left to right, starting
+/// new lines below. A vertical [`Wrap`] places them from top to bottom,
+/// starting new columns to the right.
+///
+/// [`Wrap`]: struct.Wrap.html
+#[allow(missing_debug_implementations)]
+pub struct Wrap<'a, Message, Renderer> {
+    axis: Axis,
+    spacing: u16,
+    line_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    align_items: Align,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Wrap<'a, Message, Renderer> {
+    /// Creates an empty [`Wrap`] that places its contents horizontally.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates an empty [`Wrap`] that places its contents vertically.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn new_vertical() -> Self {
+        Self::with_children(Vec::new()).axis(Axis::Vertical)
+    }
+
+    /// Creates a [`Wrap`] with the given elements.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Wrap {
+            axis: Axis::Horizontal,
+            spacing: 0,
+            line_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            align_items: Align::Start,
+            children,
+        }
+    }
+
+    /// Sets the [`Axis`] along which the [`Wrap`] places its contents.
+    ///
+    /// [`Axis`]: ../../layout/flex/enum.Axis.html
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets the spacing _between_ the elements of a line.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the spacing _between_ lines.
+    pub fn line_spacing(mut self, units: u16) -> Self {
+        self.line_spacing = units;
+        self
+    }
+
+    /// Sets the padding of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the alignment of the contents of the [`Wrap`] in the cross axis
+    /// of their line.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn align_items(mut self, align: Align) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Wrap`].
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Wrap<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Wrap<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        layout::wrap::resolve(
+            self.axis,
+            renderer,
+            &limits,
+            self.padding,
+            Spacing {
+                items: f32::from(self.spacing),
+                lines: f32::from(self.line_spacing),
+            },
+            self.align_items,
+            &self.children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.children.iter_mut().zip(layout.children()).for_each(
+            |(child, layout)| {
+                child.widget.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            },
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            &self.children,
+            layout,
+            cursor_position,
+            draw_at,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.axis.hash(state);
+        self.align_items.hash(state);
+        self.padding.hash(state);
+        self.spacing.hash(state);
+        self.line_spacing.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+
+    fn is_focused(&self) -> bool {
+        self.children.iter().any(Element::is_focused)
+    }
+}
+
+/// The renderer of a [`Wrap`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Wrap`] in your user interface.
+///
+/// [`Wrap`]: struct.Wrap.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Wrap`].
+    ///
+    /// It receives:
+    /// - the children of the [`Wrap`]
+    /// - the [`Layout`] of the [`Wrap`] and its children
+    /// - the cursor position
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        draw_at: &mut Option<std::time::Instant>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Wrap<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        wrap: Wrap<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(wrap)
+    }
+}
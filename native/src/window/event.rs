@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// A window was resized
     Resized {
@@ -12,6 +13,42 @@ pub enum Event {
         height: u32,
     },
 
+    /// A window was moved.
+    Moved {
+        /// The new logical x location of the window
+        x: i32,
+
+        /// The new logical y location of the window
+        y: i32,
+    },
+
+    /// The scale factor of a window changed, usually because it was moved
+    /// to a monitor with a different DPI.
+    ///
+    /// It is followed by a [`Resized`] event with the same logical size.
+    ///
+    /// [`Resized`]: #variant.Resized
+    ScaleFactorChanged {
+        /// The new scale factor used to lay out the window, including the
+        /// scale factor of the application
+        scale_factor: f64,
+
+        /// The new width of the window (in units)
+        width: u32,
+
+        /// The new height of the window (in units)
+        height: u32,
+    },
+
+    /// A window gained focus.
+    Focused,
+
+    /// A window lost focus.
+    Unfocused,
+
+    /// A window was destroyed.
+    Destroyed,
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
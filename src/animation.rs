@@ -0,0 +1,4 @@
+//! Animate values over time.
+pub use crate::runtime::animation::{
+    Animated, Easing, Interpolate, Keyframes, Spring, Transition, Tween,
+};
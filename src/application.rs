@@ -1,4 +1,4 @@
-use crate::window;
+use crate::{keyboard, window};
 use crate::{Color, Command, Element, Executor, Settings, Subscription};
 
 /// An interactive cross-platform application.
@@ -200,6 +200,62 @@ pub trait Application: Sized {
         1.0
     }
 
+    /// Returns the next moment the [`Application`] needs to be drawn again, even
+    /// if no events or messages are received in the meantime.
+    ///
+    /// This is useful to drive animations kept in the state of the
+    /// [`Application`], like [`Animated`] values.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Animated`]: animation/struct.Animated.html
+    fn next_draw(&self) -> Option<std::time::Instant> {
+        None
+    }
+
+    /// Returns whether the [`Application`] should be terminated.
+    ///
+    /// It is checked every time the [`Application`] is updated. Once it
+    /// returns `true`, the runtime waits for the commands in flight to
+    /// finish, processing the messages they produce, and then closes the
+    /// window and terminates the process. This is useful for tools that
+    /// should close after finishing a job, like saving a file.
+    ///
+    /// Commands that never finish keep the [`Application`] alive, so make
+    /// sure to cancel them before exiting.
+    ///
+    /// It has no effect on the Web.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn should_exit(&self) -> bool {
+        false
+    }
+
+    /// Returns the keyboard shortcuts of the [`Application`], together with
+    /// the __message__ each one of them produces.
+    ///
+    /// Shortcuts are parsed from strings like `"Ctrl+Shift+S"`. Use `Cmd` for
+    /// the command modifier of the platform, which is the logo key on macOS
+    /// and the control key elsewhere. See [`Shortcut`] to learn more.
+    ///
+    /// Shortcuts are not triggered while a widget, like a focused
+    /// [`TextInput`], has the keyboard focus. Otherwise, the key press that
+    /// triggers a shortcut does not reach the widgets.
+    ///
+    /// Currently, shortcuts only have an effect in native platforms.
+    ///
+    /// By default, it returns no shortcuts.
+    ///
+    /// [`Application`]: trait.Application.html
+    /// [`Shortcut`]: keyboard/struct.Shortcut.html
+    /// [`TextInput`]: widget/text_input/struct.TextInput.html
+    fn shortcuts(&self) -> Vec<(keyboard::Shortcut, Self::Message)> {
+        Vec::new()
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -259,6 +315,14 @@ where
     fn view(&mut self) -> Element<'_, Self::Message> {
         self.0.view()
     }
+
+    fn next_draw(&self) -> Option<std::time::Instant> {
+        self.0.next_draw()
+    }
+
+    fn shortcuts(&self) -> Vec<(keyboard::Shortcut, Self::Message)> {
+        self.0.shortcuts()
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -296,6 +360,10 @@ where
     fn scale_factor(&self) -> f64 {
         self.0.scale_factor()
     }
+
+    fn should_exit(&self) -> bool {
+        self.0.should_exit()
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -3,6 +3,9 @@ pub use crate::runtime::{executor::Null, Executor};
 
 pub use platform::Default;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::executor::{Local, LocalExecutor};
+
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     use iced_futures::{executor, futures};
@@ -13,7 +16,17 @@ mod platform {
     #[cfg(all(not(feature = "tokio"), feature = "async-std"))]
     type Executor = executor::AsyncStd;
 
-    #[cfg(not(any(feature = "tokio", feature = "async-std")))]
+    #[cfg(all(
+        not(any(feature = "tokio", feature = "async-std")),
+        feature = "smol"
+    ))]
+    type Executor = executor::Smol;
+
+    #[cfg(not(any(
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    )))]
     type Executor = executor::ThreadPool;
 
     /// A default cross-platform executor.
@@ -22,6 +35,7 @@ mod platform {
     ///   - `iced_futures::executor::Tokio` when the `tokio` feature is enabled.
     ///   - `iced_futures::executor::AsyncStd` when the `async-std` feature is
     ///     enabled.
+    ///   - `iced_futures::executor::Smol` when the `smol` feature is enabled.
     ///   - `iced_futures::executor::ThreadPool` otherwise.
     /// - On the Web, it will use `iced_futures::executor::WasmBindgen`.
     #[derive(Debug)]
@@ -39,6 +53,10 @@ mod platform {
             let _ = self.0.spawn(future);
         }
 
+        fn local(&self) -> Option<&dyn executor::LocalExecutor> {
+            self.0.local()
+        }
+
         fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
             self.0.enter(f)
         }
@@ -55,6 +73,7 @@ mod platform {
     ///   - `iced_futures::executor::Tokio` when the `tokio` feature is enabled.
     ///   - `iced_futures::executor::AsyncStd` when the `async-std` feature is
     ///     enabled.
+    ///   - `iced_futures::executor::Smol` when the `smol` feature is enabled.
     ///   - `iced_futures::executor::ThreadPool` otherwise.
     /// - On the Web, it will use `iced_futures::executor::WasmBindgen`.
     #[derive(Debug)]
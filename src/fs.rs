@@ -0,0 +1,15 @@
+//! Listen to changes in the file system.
+use crate::Subscription;
+
+pub use iced_futures::fs::Event;
+
+/// Returns a [`Subscription`] that produces an [`Event`] every time the
+/// given path changes.
+///
+/// If the path is a directory, its entries are watched, but not recursively.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+/// [`Event`]: enum.Event.html
+pub fn watch(path: impl Into<std::path::PathBuf>) -> Subscription<Event> {
+    iced_futures::fs::watch(path)
+}
@@ -192,6 +192,12 @@ pub mod settings;
 pub mod widget;
 pub mod window;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod animation;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod touch;
+
 #[cfg(all(
     any(feature = "tokio", feature = "async-std"),
     not(target_arch = "wasm32")
@@ -199,6 +205,20 @@ pub mod window;
 #[cfg_attr(docsrs, doc(cfg(any(feature = "tokio", feature = "async-std"))))]
 pub mod time;
 
+#[cfg(all(
+    any(feature = "tokio", feature = "async-std"),
+    not(target_arch = "wasm32")
+))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "tokio", feature = "async-std"))))]
+pub mod net;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod process;
+
+#[cfg(all(feature = "fs", target_os = "linux"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "fs")))]
+pub mod fs;
+
 #[cfg(all(
     not(target_arch = "wasm32"),
     not(feature = "glow"),
@@ -235,5 +255,6 @@ pub use settings::Settings;
 
 pub use runtime::{
     futures, Align, Background, Color, Command, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Subscription, Vector, VerticalAlignment,
+    Length, Padding, Point, Rectangle, Size, Subscription, Vector,
+    VerticalAlignment,
 };
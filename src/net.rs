@@ -0,0 +1,29 @@
+//! Talk to other processes over TCP or Unix domain sockets.
+use crate::Subscription;
+
+pub use iced_futures::net::{Address, Connection, Event, Framing};
+
+/// Returns a [`Subscription`] that connects to the given [`Address`] and
+/// produces an [`Event`] for every framed message received.
+///
+/// Use the [`Connection`] produced by [`Event::Connected`] to send messages.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+/// [`Address`]: enum.Address.html
+/// [`Event`]: enum.Event.html
+/// [`Connection`]: struct.Connection.html
+/// [`Event::Connected`]: enum.Event.html#variant.Connected
+pub fn connect(address: Address, framing: Framing) -> Subscription<Event> {
+    iced_futures::net::connect(address, framing)
+}
+
+/// Returns a [`Subscription`] that listens on the given [`Address`] and
+/// produces an [`Event`] for every connection accepted and every framed
+/// message received.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+/// [`Address`]: enum.Address.html
+/// [`Event`]: enum.Event.html
+pub fn listen(address: Address, framing: Framing) -> Subscription<Event> {
+    iced_futures::net::listen(address, framing)
+}
@@ -0,0 +1,17 @@
+//! Run child processes and listen to their output.
+use crate::Subscription;
+
+pub use iced_futures::process::{Event, Process};
+
+/// Returns a [`Subscription`] that runs the given [`Process`] and produces
+/// an [`Event`] for every line of its output and for its exit.
+///
+/// The [`Process`] is killed once the [`Subscription`] is not returned by
+/// your application anymore.
+///
+/// [`Subscription`]: ../subscription/struct.Subscription.html
+/// [`Process`]: struct.Process.html
+/// [`Event`]: enum.Event.html
+pub fn run(process: Process) -> Subscription<Event> {
+    iced_futures::process::run(process)
+}
@@ -1,6 +1,7 @@
 use crate::executor;
 use crate::{
-    Application, Color, Command, Element, Error, Settings, Subscription,
+    keyboard, Application, Color, Command, Element, Error, Settings,
+    Subscription,
 };
 
 /// A sandboxed [`Application`].
@@ -152,6 +153,45 @@ pub trait Sandbox {
         1.0
     }
 
+    /// Returns the next moment the [`Sandbox`] needs to be drawn again, even
+    /// if no events or messages are received in the meantime.
+    ///
+    /// This is useful to drive animations kept in the state of the
+    /// [`Sandbox`], like [`Animated`] values.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Sandbox`]: trait.Sandbox.html
+    /// [`Animated`]: animation/struct.Animated.html
+    fn next_draw(&self) -> Option<std::time::Instant> {
+        None
+    }
+
+    /// Returns whether the [`Sandbox`] should be terminated.
+    ///
+    /// It is checked every time the [`Sandbox`] is updated. Once it returns
+    /// `true`, the window will be closed and the process terminated.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Sandbox`]: trait.Sandbox.html
+    fn should_exit(&self) -> bool {
+        false
+    }
+
+    /// Returns the keyboard shortcuts of the [`Sandbox`], together with the
+    /// __message__ each one of them produces.
+    ///
+    /// See [`Application::shortcuts`] to learn more.
+    ///
+    /// By default, it returns no shortcuts.
+    ///
+    /// [`Sandbox`]: trait.Sandbox.html
+    /// [`Application::shortcuts`]: trait.Application.html#method.shortcuts
+    fn shortcuts(&self) -> Vec<(keyboard::Shortcut, Self::Message)> {
+        Vec::new()
+    }
+
     /// Runs the [`Sandbox`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -205,4 +245,16 @@ where
     fn scale_factor(&self) -> f64 {
         T::scale_factor(self)
     }
+
+    fn next_draw(&self) -> Option<std::time::Instant> {
+        T::next_draw(self)
+    }
+
+    fn should_exit(&self) -> bool {
+        T::should_exit(self)
+    }
+
+    fn shortcuts(&self) -> Vec<(keyboard::Shortcut, T::Message)> {
+        T::shortcuts(self)
+    }
 }
@@ -19,8 +19,9 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, Column, Row, Space, Text,
+        button, checkbox, container, grid, pane_grid, pick_list, progress_bar,
+        radio, responsive, rule, scrollable, slider, spinner, stack,
+        text_input, toggler, Column, Row, Space, Text, Wrap,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -44,10 +45,11 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
-        pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput,
+        button::Button, checkbox::Checkbox, container::Container, grid::Grid,
+        image::Image, pane_grid::PaneGrid, pick_list::PickList,
+        progress_bar::ProgressBar, radio::Radio, responsive::Responsive,
+        rule::Rule, scrollable::Scrollable, slider::Slider, spinner::Spinner,
+        stack::Stack, svg::Svg, text_input::TextInput, toggler::Toggler,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
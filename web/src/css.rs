@@ -1,5 +1,5 @@
 //! Style your widgets.
-use crate::{bumpalo, Align, Background, Color, Length};
+use crate::{bumpalo, Align, Background, Color, Length, Padding};
 
 use std::collections::BTreeMap;
 
@@ -13,7 +13,7 @@ pub enum Rule {
     Row,
 
     /// Padding of the container
-    Padding(u16),
+    Padding(Padding),
 
     /// Spacing between elements
     Spacing(u16),
@@ -27,7 +27,10 @@ impl Rule {
         match self {
             Rule::Column => String::from("c"),
             Rule::Row => String::from("r"),
-            Rule::Padding(padding) => format!("p-{}", padding),
+            Rule::Padding(padding) => format!(
+                "p-{}-{}-{}-{}",
+                padding.top, padding.right, padding.bottom, padding.left
+            ),
             Rule::Spacing(spacing) => format!("s-{}", spacing),
         }
     }
@@ -51,9 +54,12 @@ impl Rule {
             }
             Rule::Padding(padding) => bumpalo::format!(
                 in bump,
-                ".{} {{ box-sizing: border-box; padding: {}px }}",
+                ".{} {{ box-sizing: border-box; padding: {}px {}px {}px {}px }}",
                 class,
-                padding
+                padding.top,
+                padding.right,
+                padding.bottom,
+                padding.left
             )
             .into_bump_str(),
             Rule::Spacing(spacing) => bumpalo::format!(
@@ -139,6 +145,7 @@ pub fn length(length: Length) -> String {
     match length {
         Length::Shrink => String::from("auto"),
         Length::Units(px) => format!("{}px", px),
+        Length::Percent(percent) => format!("{}%", percent),
         Length::Fill | Length::FillPortion(_) => String::from("100%"),
     }
 }
@@ -26,6 +26,7 @@ pub mod radio;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
+pub mod toggler;
 
 mod column;
 mod row;
@@ -51,6 +52,7 @@ pub use progress_bar::ProgressBar;
 pub use radio::Radio;
 pub use row::Row;
 pub use space::Space;
+pub use toggler::Toggler;
 
 /// A component that displays information and allows interaction.
 ///
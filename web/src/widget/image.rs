@@ -103,6 +103,12 @@ impl<Message> Widget<Message> for Image {
                     bumpalo::format!(in bump, "{}px", px).into_bump_str(),
                 );
             }
+            Length::Percent(percent) => {
+                image = image.attr(
+                    "width",
+                    bumpalo::format!(in bump, "{}%", percent).into_bump_str(),
+                );
+            }
         }
 
         // TODO: Complete styling
@@ -1,5 +1,7 @@
 //! Navigate an endless amount of content with a scrollbar.
-use crate::{bumpalo, css, Align, Bus, Column, Css, Element, Length, Widget};
+use crate::{
+    bumpalo, css, Align, Bus, Column, Css, Element, Length, Padding, Widget,
+};
 
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
@@ -44,8 +46,8 @@ impl<'a, Message> Scrollable<'a, Message> {
     /// Sets the padding of the [`Scrollable`].
     ///
     /// [`Scrollable`]: struct.Scrollable.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.content = self.content.padding(units);
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.content = self.content.padding(padding);
         self
     }
 
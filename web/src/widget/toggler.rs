@@ -0,0 +1,270 @@
+//! Show toggle controls using togglers.
+//!
+//! A [`Toggler`] has some local [`State`].
+//!
+//! [`Toggler`]: struct.Toggler.html
+//! [`State`]: struct.State.html
+use crate::{css, Bus, Css, Element, Length, Widget};
+
+pub use iced_style::toggler::{Style, StyleSheet};
+
+use dodrio::bumpalo;
+use std::rc::Rc;
+
+/// A toggler widget, an on/off switch with a label.
+///
+/// # Example
+///
+/// ```
+/// # use iced_web::{toggler, Toggler};
+///
+/// pub enum Message {
+///     TogglerToggled(bool),
+/// }
+///
+/// let mut state = toggler::State::new();
+/// let is_active = true;
+///
+/// Toggler::new(&mut state, is_active, "Toggle me!", Message::TogglerToggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Toggler<Message> {
+    is_active: bool,
+    on_toggle: Rc<dyn Fn(bool) -> Message>,
+    label: String,
+    label_position: LabelPosition,
+    id: Option<String>,
+    width: Length,
+    size: u16,
+    spacing: u16,
+    style: Box<dyn StyleSheet>,
+}
+
+impl<Message> Toggler<Message> {
+    /// Creates a new [`Toggler`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`Toggler`]
+    ///   * a boolean describing whether the [`Toggler`] is active or not
+    ///   * the label of the [`Toggler`]
+    ///   * a function that will be called when the [`Toggler`] is toggled. It
+    ///     will receive the new state of the [`Toggler`] and must produce a
+    ///     `Message`.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        _state: &mut State,
+        is_active: bool,
+        label: impl Into<String>,
+        f: F,
+    ) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        Toggler {
+            is_active,
+            on_toggle: Rc::new(f),
+            label: label.into(),
+            label_position: LabelPosition::Right,
+            id: None,
+            width: Length::Shrink,
+            size: 20,
+            spacing: 15,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`LabelPosition`] of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    /// [`LabelPosition`]: enum.LabelPosition.html
+    pub fn label_position(mut self, label_position: LabelPosition) -> Self {
+        self.label_position = label_position;
+        self
+    }
+
+    /// Sets the size of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the width of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the [`Toggler`] and the text.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the style of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the id of the [`Toggler`].
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+}
+
+/// The position of the label of a [`Toggler`].
+///
+/// [`Toggler`]: struct.Toggler.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LabelPosition {
+    /// The label is placed to the left of the switch.
+    Left,
+
+    /// The label is placed to the right of the switch.
+    Right,
+}
+
+/// The local state of a [`Toggler`].
+///
+/// [`Toggler`]: struct.Toggler.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State
+    }
+}
+
+impl<Message> Widget<Message> for Toggler<Message>
+where
+    Message: 'static,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+        use dodrio::bumpalo::collections::String;
+
+        let toggler_label =
+            String::from_str_in(&self.label, bump).into_bump_str();
+
+        let event_bus = bus.clone();
+        let on_toggle = self.on_toggle.clone();
+        let is_active = self.is_active;
+
+        // TODO: State-based styling
+        let style = self.style.active(self.is_active);
+
+        let row_class = style_sheet.insert(bump, css::Rule::Row);
+
+        let spacing_class =
+            style_sheet.insert(bump, css::Rule::Spacing(self.spacing));
+
+        let label = if let Some(id) = &self.id {
+            let id = String::from_str_in(id, bump).into_bump_str();
+
+            label(bump).attr("id", id)
+        } else {
+            label(bump)
+        };
+
+        let size = self.size;
+        let space = size / 10;
+        let knob_size = size - 2 * space;
+
+        let knob = span(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "position: absolute; top: {}px; left: {}px; \
+                    width: {}px; height: {}px; border-radius: 50%; \
+                    background: {}; border: 1px solid {}; \
+                    box-sizing: border-box; transition: left 150ms ease-in-out",
+                    space,
+                    if is_active { 2 * size - knob_size - space } else { space },
+                    knob_size,
+                    knob_size,
+                    css::color(style.foreground),
+                    css::color(style.foreground_border.unwrap_or(style.foreground))
+                )
+                .into_bump_str(),
+            )
+            .finish();
+
+        let switch = span(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "position: relative; display: inline-block; \
+                    flex-shrink: 0; width: {}px; height: {}px; \
+                    border-radius: {}px; background: {}; \
+                    border: 1px solid {}; box-sizing: border-box; \
+                    transition: background 150ms ease-in-out",
+                    2 * size,
+                    size,
+                    size / 2,
+                    css::color(style.background),
+                    css::color(style.background_border.unwrap_or(style.background))
+                )
+                .into_bump_str(),
+            )
+            .children(vec![knob])
+            .finish();
+
+        let children = match self.label_position {
+            LabelPosition::Left => vec![text(toggler_label), switch],
+            LabelPosition::Right => vec![switch, text(toggler_label)],
+        };
+
+        label
+            .attr(
+                "class",
+                bumpalo::format!(in bump, "{} {}", row_class, spacing_class)
+                    .into_bump_str(),
+            )
+            .attr(
+                "style",
+                bumpalo::format!(in bump, "width: {}; align-items: center; cursor: pointer", css::length(self.width))
+                    .into_bump_str(),
+            )
+            .on("click", move |_root, vdom, _event| {
+                let msg = on_toggle(!is_active);
+                event_bus.publish(msg);
+
+                vdom.schedule_render();
+            })
+            .children(children)
+            .finish()
+    }
+}
+
+impl<'a, Message> From<Toggler<Message>> for Element<'a, Message>
+where
+    Message: 'static,
+{
+    fn from(toggler: Toggler<Message>) -> Element<'a, Message> {
+        Element::new(toggler)
+    }
+}
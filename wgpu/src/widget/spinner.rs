@@ -0,0 +1,10 @@
+//! Show an endless animation while some work is being done.
+use crate::Renderer;
+
+pub use iced_graphics::spinner::{Style, StyleSheet};
+
+/// A circular spinner that indicates some work is being done.
+///
+/// This is an alias of an `iced_native` spinner with an
+/// `iced_wgpu::Renderer`.
+pub type Spinner = iced_native::Spinner<Renderer>;
@@ -0,0 +1,14 @@
+//! Show toggle controls using togglers.
+//!
+//! A [`Toggler`] has some local [`State`].
+//!
+//! [`Toggler`]: type.Toggler.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::toggler::{LabelPosition, State, Style, StyleSheet};
+
+/// A toggler widget, an on/off switch with a label.
+///
+/// This is an alias of an `iced_native` toggler with an `iced_wgpu::Renderer`.
+pub type Toggler<'a, Message> = iced_native::Toggler<'a, Message, Renderer>;
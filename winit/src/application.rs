@@ -2,13 +2,26 @@
 use crate::conversion;
 use crate::mouse;
 use crate::{
-    Clipboard, Color, Command, Debug, Error, Executor, Mode, Proxy, Runtime,
-    Settings, Size, Subscription,
+    Clipboard, Color, Command, Debug, Error, Executor, Mode, Proxy, Recording,
+    Runtime, Settings, Size, Subscription,
 };
 use iced_graphics::window;
 use iced_graphics::Viewport;
 use iced_native::program::{self, Program};
 
+use std::path::{Path, PathBuf};
+
+/// The environment variable holding the file where the performance trace of
+/// the debug view is exported.
+const TRACE_VARIABLE: &str = "ICED_TRACE";
+
+/// The file where the performance trace is exported on demand when
+/// [`TRACE_VARIABLE`] is unset.
+///
+/// [`TRACE_VARIABLE`]: constant.TRACE_VARIABLE.html
+#[cfg(feature = "debug")]
+const DEFAULT_TRACE_PATH: &str = "iced-trace.json";
+
 /// An interactive, native cross-platform application.
 ///
 /// This trait is the main entrypoint of Iced. Once implemented, you can run
@@ -21,6 +34,25 @@ use iced_native::program::{self, Program};
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
 /// can be toggled by pressing `F12`.
 ///
+/// Pressing `Shift+F12` toggles a layout inspector, which highlights the
+/// widget under the cursor and shows its bounds, [`Length`] settings and
+/// layout limits. Pressing `Ctrl+F12` prints the whole layout tree to the
+/// standard error.
+///
+/// The timings measured by the debug view are also recorded in a performance
+/// trace. Pressing `Alt+F12` exports it to the file in the `ICED_TRACE`
+/// environment variable, or to `iced-trace.json` if unset. When `ICED_TRACE`
+/// is set, the trace is also exported when the application exits. See
+/// [`Debug::export_trace`] for the supported formats. The trace keeps the
+/// last 10 000 spans, or the amount in the `ICED_TRACE_CAPACITY` environment
+/// variable.
+///
+/// When the `record` feature is enabled, the events of an [`Application`] can
+/// be recorded to a file and replayed later. See [`Recording`] for details.
+///
+/// [`Length`]: enum.Length.html
+/// [`Debug::export_trace`]: struct.Debug.html#method.export_trace
+/// [`Recording`]: struct.Recording.html
 /// [`Application`]: trait.Application.html
 pub trait Application: Program {
     /// The data needed to initialize your [`Application`].
@@ -101,6 +133,23 @@ pub trait Application: Program {
     fn scale_factor(&self) -> f64 {
         1.0
     }
+
+    /// Returns whether the [`Application`] should be terminated.
+    ///
+    /// The runtime checks it every time the [`Application`] is updated. Once
+    /// it returns `true`, the runtime waits for the commands in flight to
+    /// finish, processing the messages they produce, and then closes the
+    /// window and leaves the event loop, terminating the process.
+    ///
+    /// Commands that never finish keep the [`Application`] alive, so make
+    /// sure to cancel them before exiting.
+    ///
+    /// By default, it returns `false`.
+    ///
+    /// [`Application`]: trait.Application.html
+    fn should_exit(&self) -> bool {
+        false
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -125,9 +174,11 @@ where
     debug.startup_started();
 
     let event_loop = EventLoop::with_user_event();
+    let proxy = Proxy::new(event_loop.create_proxy());
+    let waker = proxy.waker();
+
     let mut runtime = {
         let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
-        let proxy = Proxy::new(event_loop.create_proxy());
 
         Runtime::new(executor, proxy)
     };
@@ -138,6 +189,7 @@ where
 
     let subscription = application.subscription();
     runtime.track(subscription);
+    runtime.run_local(&waker);
 
     let mut title = application.title();
     let mut mode = application.mode();
@@ -154,7 +206,9 @@ where
     // TODO: Encode cursor availability in the type-system
     let mut cursor_position = winit::dpi::PhysicalPosition::new(-1.0, -1.0);
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut ime_area = None;
     let mut modifiers = winit::event::ModifiersState::default();
+    let mut primary_finger = None;
 
     let physical_size = window.inner_size();
     let mut viewport = Viewport::with_physical_size(
@@ -162,6 +216,7 @@ where
         window.scale_factor() * scale_factor,
     );
     let mut resized = false;
+    let mut is_exiting = false;
 
     let (mut compositor, mut renderer) = C::new(compositor_settings)?;
 
@@ -182,8 +237,20 @@ where
     );
     debug.startup_finished();
 
+    let mut recording = Recording::from_environment(&window, &viewport);
+
     event_loop.run(move |event, _, control_flow| match event {
         event::Event::MainEventsCleared => {
+            for event in recording.replay(
+                &window,
+                &mut viewport,
+                &mut resized,
+                &mut cursor_position,
+            ) {
+                state.queue_event(event.clone());
+                runtime.broadcast(event);
+            }
+
             let pending_update = if let Some(nd) = state.next_draw() {
                 nd < std::time::Instant::now()
             } else {
@@ -206,6 +273,22 @@ where
                 )
             });
 
+            // Place the candidate window of input methods next to the text
+            // cursor of the focused text input
+            let new_ime_area =
+                iced_native::Renderer::take_ime_area(&mut renderer);
+
+            if new_ime_area != ime_area {
+                if let Some(area) = new_ime_area {
+                    window.set_ime_position(conversion::ime_position(
+                        area,
+                        viewport.scale_factor(),
+                    ));
+                }
+
+                ime_area = new_ime_area;
+            }
+
             // If the application was updated
             if let Some(command) = command {
                 runtime.spawn(command);
@@ -215,6 +298,7 @@ where
                 // Update subscriptions
                 let subscription = program.subscription();
                 runtime.track(subscription);
+                runtime.run_local(&waker);
 
                 // Update window title
                 let new_title = program.title();
@@ -269,6 +353,12 @@ where
 
                     scale_factor = new_scale_factor;
                 }
+
+                // Leave the event loop once the application is done and its
+                // pending commands finish
+                if state.program().should_exit() {
+                    is_exiting = true;
+                }
             }
 
             if let Some(next_draw) = state.next_draw() {
@@ -277,9 +367,12 @@ where
             };
             window.request_redraw();
         }
-        event::Event::UserEvent(message) => {
+        event::Event::UserEvent(Some(message)) => {
             state.queue_message(message);
         }
+        event::Event::UserEvent(None) => {
+            runtime.run_local(&waker);
+        }
         event::Event::RedrawRequested(_) => {
             debug.render_started();
 
@@ -314,8 +407,6 @@ where
                 mouse_interaction = new_mouse_interaction;
             }
 
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
             if let Some(next_draw) = state.next_draw() {
                 *control_flow =
                     winit::event_loop::ControlFlow::WaitUntil(next_draw);
@@ -341,9 +432,67 @@ where
                 &window_event,
                 viewport.scale_factor(),
                 modifiers,
-            ) {
+            )
+            .filter(|_| !recording.is_replaying())
+            {
+                let emulated_events = match event {
+                    iced_native::Event::Touch(touch_event) => {
+                        conversion::touch_to_mouse(
+                            touch_event,
+                            &mut primary_finger,
+                        )
+                    }
+                    _ => Vec::new(),
+                };
+
+                // A new scale factor also changes the logical size of the
+                // window
+                let resized_event = match event {
+                    iced_native::Event::Window(
+                        iced_native::window::Event::ScaleFactorChanged {
+                            width,
+                            height,
+                            ..
+                        },
+                    ) => Some(iced_native::Event::Window(
+                        iced_native::window::Event::Resized { width, height },
+                    )),
+                    _ => None,
+                };
+
+                recording.record(&event);
+
                 state.queue_event(event.clone());
                 runtime.broadcast(event);
+
+                if let Some(event) = resized_event {
+                    recording.record(&event);
+                    state.queue_event(event.clone());
+                    runtime.broadcast(event);
+                }
+
+                for mouse_event in emulated_events {
+                    match mouse_event {
+                        mouse::Event::CursorMoved { x, y } => {
+                            cursor_position = winit::dpi::LogicalPosition::new(
+                                f64::from(x),
+                                f64::from(y),
+                            )
+                            .to_physical(viewport.scale_factor());
+                        }
+                        mouse::Event::CursorLeft => {
+                            cursor_position =
+                                winit::dpi::PhysicalPosition::new(-1.0, -1.0);
+                        }
+                        _ => {}
+                    }
+
+                    let event = iced_native::Event::Mouse(mouse_event);
+
+                    recording.record(&event);
+                    state.queue_event(event.clone());
+                    runtime.broadcast(event);
+                }
             };
 
             if *control_flow == ControlFlow::Wait {
@@ -353,16 +502,63 @@ where
                 };
             };
         }
+        event::Event::LoopDestroyed => {
+            export_trace(&debug, None);
+        }
         _ => {
+            if is_exiting {
+                // Commands do not notify the event loop when they finish
+                // without producing a message, so we poll them
+                *control_flow = if runtime.has_pending_commands() {
+                    ControlFlow::WaitUntil(
+                        std::time::Instant::now()
+                            + std::time::Duration::from_millis(10),
+                    )
+                } else {
+                    ControlFlow::Exit
+                };
+
+                return;
+            }
+
             *control_flow = if let Some(next_draw) = state.next_draw() {
                 ControlFlow::WaitUntil(next_draw)
             } else {
                 ControlFlow::Wait
-            }
+            };
+
+            recording.wake_up(control_flow);
         }
     })
 }
 
+/// Exports the performance trace of the provided [`Debug`] to the file in the
+/// `ICED_TRACE` environment variable, or to the fallback path if unset.
+///
+/// Nothing is exported if there is neither a variable nor a fallback path.
+///
+/// [`Debug`]: struct.Debug.html
+pub fn export_trace(debug: &Debug, fallback: Option<&Path>) {
+    let path = match std::env::var_os(TRACE_VARIABLE) {
+        Some(path) => PathBuf::from(path),
+        None => match fallback {
+            Some(path) => path.to_path_buf(),
+            None => return,
+        },
+    };
+
+    match debug.export_trace(&path) {
+        Ok(()) => {
+            log::info!("Performance trace exported to {}", path.display())
+        }
+        Err(error) => log::error!(
+            "Failed to export performance trace to {}: {}",
+            path.display(),
+            error
+        ),
+    }
+}
+
 /// Handles a `WindowEvent` and mutates the provided control flow, keyboard
 /// modifiers, viewport, and resized flag accordingly.
 pub fn handle_window_event(
@@ -426,6 +622,38 @@ pub fn handle_window_event(
             *control_flow = ControlFlow::Exit;
         }
         #[cfg(feature = "debug")]
+        WindowEvent::KeyboardInput {
+            input:
+                winit::event::KeyboardInput {
+                    virtual_keycode: Some(winit::event::VirtualKeyCode::F12),
+                    state: winit::event::ElementState::Pressed,
+                    ..
+                },
+            ..
+        } if modifiers.shift() => _debug.toggle_inspector(),
+        #[cfg(feature = "debug")]
+        WindowEvent::KeyboardInput {
+            input:
+                winit::event::KeyboardInput {
+                    virtual_keycode: Some(winit::event::VirtualKeyCode::F12),
+                    state: winit::event::ElementState::Pressed,
+                    ..
+                },
+            ..
+        } if modifiers.ctrl() => _debug.request_layout_dump(),
+        #[cfg(feature = "debug")]
+        WindowEvent::KeyboardInput {
+            input:
+                winit::event::KeyboardInput {
+                    virtual_keycode: Some(winit::event::VirtualKeyCode::F12),
+                    state: winit::event::ElementState::Pressed,
+                    ..
+                },
+            ..
+        } if modifiers.alt() => {
+            export_trace(_debug, Some(Path::new(DEFAULT_TRACE_PATH)))
+        }
+        #[cfg(feature = "debug")]
         WindowEvent::KeyboardInput {
             input:
                 winit::event::KeyboardInput {
@@ -4,10 +4,14 @@
 //! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
 use crate::{
     keyboard::{self, KeyCode, ModifiersState},
-    mouse, window, Event, Mode, Point,
+    mouse, touch, window, Event, Mode, Point, Rectangle,
 };
 
 /// Converts a winit window event into an iced event.
+///
+/// The `scale_factor` must be the one used to lay out the window, which
+/// combines the scale factor of the window and the one of the application.
+/// On a `ScaleFactorChanged` event, it must already be the new one.
 pub fn window_event(
     event: &winit::event::WindowEvent<'_>,
     scale_factor: f64,
@@ -27,11 +31,26 @@ pub fn window_event(
         WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
             let logical_size = new_inner_size.to_logical(scale_factor);
 
-            Some(Event::Window(window::Event::Resized {
+            Some(Event::Window(window::Event::ScaleFactorChanged {
+                scale_factor,
                 width: logical_size.width,
                 height: logical_size.height,
             }))
         }
+        WindowEvent::Moved(position) => {
+            let position = position.to_logical(scale_factor);
+
+            Some(Event::Window(window::Event::Moved {
+                x: position.x,
+                y: position.y,
+            }))
+        }
+        WindowEvent::Focused(focused) => Some(Event::Window(if *focused {
+            window::Event::Focused
+        } else {
+            window::Event::Unfocused
+        })),
+        WindowEvent::Destroyed => Some(Event::Window(window::Event::Destroyed)),
         WindowEvent::CursorMoved { position, .. } => {
             let position = position.to_logical::<f64>(scale_factor);
 
@@ -109,6 +128,9 @@ pub fn window_event(
         WindowEvent::ModifiersChanged(new_modifiers) => Some(Event::Keyboard(
             keyboard::Event::ModifiersChanged(modifiers_state(*new_modifiers)),
         )),
+        WindowEvent::Touch(touch) => {
+            Some(Event::Touch(touch_event(*touch, scale_factor)))
+        }
         WindowEvent::HoveredFile(path) => {
             Some(Event::Window(window::Event::FileHovered(path.clone())))
         }
@@ -122,6 +144,93 @@ pub fn window_event(
     }
 }
 
+/// Converts a `Touch` from [`winit`] to an [`iced_native`] touch event.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn touch_event(
+    touch: winit::event::Touch,
+    scale_factor: f64,
+) -> touch::Event {
+    let id = touch::Finger(touch.id);
+    let position = {
+        let location = touch.location.to_logical::<f64>(scale_factor);
+
+        Point::new(location.x as f32, location.y as f32)
+    };
+
+    match touch.phase {
+        winit::event::TouchPhase::Started => {
+            touch::Event::FingerPressed { id, position }
+        }
+        winit::event::TouchPhase::Moved => {
+            touch::Event::FingerMoved { id, position }
+        }
+        winit::event::TouchPhase::Ended => {
+            touch::Event::FingerLifted { id, position }
+        }
+        winit::event::TouchPhase::Cancelled => {
+            touch::Event::FingerLost { id, position }
+        }
+    }
+}
+
+/// Emulates a mouse with the primary finger of a touch interaction.
+///
+/// The primary finger is the first one pressed while no other finger was on
+/// the screen. It moves the cursor and presses the left mouse button, which
+/// lets existing widgets work on touch screens. The events of any other
+/// finger produce no mouse events.
+pub fn touch_to_mouse(
+    event: touch::Event,
+    primary_finger: &mut Option<touch::Finger>,
+) -> Vec<mouse::Event> {
+    let cursor_moved = |position: Point| mouse::Event::CursorMoved {
+        x: position.x,
+        y: position.y,
+    };
+
+    match event {
+        touch::Event::FingerPressed { id, position }
+            if primary_finger.is_none() =>
+        {
+            *primary_finger = Some(id);
+
+            vec![
+                cursor_moved(position),
+                mouse::Event::ButtonPressed(mouse::Button::Left),
+            ]
+        }
+        touch::Event::FingerMoved { id, position }
+            if *primary_finger == Some(id) =>
+        {
+            vec![cursor_moved(position)]
+        }
+        touch::Event::FingerLifted { id, position }
+            if *primary_finger == Some(id) =>
+        {
+            *primary_finger = None;
+
+            vec![
+                cursor_moved(position),
+                mouse::Event::ButtonReleased(mouse::Button::Left),
+            ]
+        }
+        touch::Event::FingerLost { id, .. } if *primary_finger == Some(id) => {
+            *primary_finger = None;
+
+            // The interaction was aborted. The button is released so widgets
+            // do not stay pressed, and the cursor leaves so the release does
+            // not trigger any action.
+            vec![
+                mouse::Event::ButtonReleased(mouse::Button::Left),
+                mouse::Event::CursorLeft,
+            ]
+        }
+        _ => Vec::new(),
+    }
+}
+
 /// Converts a [`Mode`] to a [`winit`] fullscreen mode.
 ///
 /// [`Mode`]: ../enum.Mode.html
@@ -201,6 +310,19 @@ pub fn cursor_position(
     Point::new(logical_position.x, logical_position.y)
 }
 
+/// Converts the area of a text cursor to the physical position where the
+/// candidate window of an input method should be placed.
+pub fn ime_position(
+    area: Rectangle,
+    scale_factor: f64,
+) -> winit::dpi::PhysicalPosition<f64> {
+    winit::dpi::LogicalPosition::new(
+        f64::from(area.x),
+        f64::from(area.y + area.height),
+    )
+    .to_physical(scale_factor)
+}
+
 /// Converts a `VirtualKeyCode` from [`winit`] to an [`iced_native`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -384,3 +506,161 @@ pub(crate) fn is_private_use_character(c: char) -> bool {
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{
+        renderer, Button, Cache, Length, Size, Text, UserInterface,
+    };
+
+    fn finger_pressed(id: u64, x: f32, y: f32) -> touch::Event {
+        touch::Event::FingerPressed {
+            id: touch::Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    fn finger_lifted(id: u64, x: f32, y: f32) -> touch::Event {
+        touch::Event::FingerLifted {
+            id: touch::Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    fn finger_lost(id: u64, x: f32, y: f32) -> touch::Event {
+        touch::Event::FingerLost {
+            id: touch::Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    /// Feeds every touch event, in its own frame, to a button covering the
+    /// whole window and returns the amount of times it was clicked.
+    ///
+    /// Like the event loop, the cursor position is updated with the
+    /// emulated events of a frame before the user interface processes them.
+    fn clicks(touch_events: Vec<touch::Event>) -> usize {
+        let mut renderer = renderer::Null::new();
+        let mut state = iced_native::button::State::new();
+        let mut user_interface = UserInterface::build(
+            Button::new(&mut state, Text::new("Tap"))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .on_press(()),
+            Size::new(100.0, 100.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        let mut primary_finger = None;
+        let mut cursor_position = Point::new(-1.0, -1.0);
+        let mut clicks = 0;
+
+        for touch_event in touch_events {
+            let events: Vec<_> =
+                touch_to_mouse(touch_event, &mut primary_finger)
+                    .into_iter()
+                    .map(|mouse_event| {
+                        match mouse_event {
+                            mouse::Event::CursorMoved { x, y } => {
+                                cursor_position = Point::new(x, y);
+                            }
+                            mouse::Event::CursorLeft => {
+                                cursor_position = Point::new(-1.0, -1.0);
+                            }
+                            _ => {}
+                        }
+
+                        Event::Mouse(mouse_event)
+                    })
+                    .collect();
+
+            clicks += user_interface
+                .update(&events, cursor_position, None, &renderer)
+                .len();
+        }
+
+        clicks
+    }
+
+    #[test]
+    fn primary_finger_emulates_the_left_button() {
+        let mut primary_finger = None;
+
+        assert_eq!(
+            touch_to_mouse(finger_pressed(0, 10.0, 20.0), &mut primary_finger),
+            vec![
+                mouse::Event::CursorMoved { x: 10.0, y: 20.0 },
+                mouse::Event::ButtonPressed(mouse::Button::Left),
+            ]
+        );
+        assert_eq!(
+            touch_to_mouse(finger_lifted(0, 15.0, 20.0), &mut primary_finger),
+            vec![
+                mouse::Event::CursorMoved { x: 15.0, y: 20.0 },
+                mouse::Event::ButtonReleased(mouse::Button::Left),
+            ]
+        );
+        assert_eq!(primary_finger, None);
+    }
+
+    #[test]
+    fn other_fingers_are_ignored() {
+        let mut primary_finger = None;
+
+        let _ =
+            touch_to_mouse(finger_pressed(0, 10.0, 20.0), &mut primary_finger);
+
+        assert!(touch_to_mouse(
+            finger_pressed(1, 50.0, 50.0),
+            &mut primary_finger
+        )
+        .is_empty());
+        assert!(touch_to_mouse(
+            finger_lost(1, 50.0, 50.0),
+            &mut primary_finger
+        )
+        .is_empty());
+        assert_eq!(primary_finger, Some(touch::Finger(0)));
+    }
+
+    #[test]
+    fn lost_primary_finger_releases_the_button_and_leaves() {
+        let mut primary_finger = None;
+
+        let _ =
+            touch_to_mouse(finger_pressed(0, 10.0, 20.0), &mut primary_finger);
+
+        assert_eq!(
+            touch_to_mouse(finger_lost(0, 10.0, 20.0), &mut primary_finger),
+            vec![
+                mouse::Event::ButtonReleased(mouse::Button::Left),
+                mouse::Event::CursorLeft,
+            ]
+        );
+        assert_eq!(primary_finger, None);
+    }
+
+    #[test]
+    fn tap_clicks() {
+        assert_eq!(
+            clicks(vec![
+                finger_pressed(0, 50.0, 50.0),
+                finger_lifted(0, 50.0, 50.0)
+            ]),
+            1
+        );
+    }
+
+    #[test]
+    fn lost_finger_does_not_click() {
+        assert_eq!(
+            clicks(vec![
+                finger_pressed(0, 50.0, 50.0),
+                finger_lost(0, 50.0, 50.0)
+            ]),
+            0
+        );
+    }
+}
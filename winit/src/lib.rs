@@ -19,7 +19,9 @@
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
-#![forbid(rust_2018_idioms)]
+// The `serde` derives allow lints that cannot be allowed once forbidden
+#![cfg_attr(not(feature = "record"), forbid(rust_2018_idioms))]
+#![cfg_attr(feature = "record", deny(rust_2018_idioms))]
 
 #[doc(no_inline)]
 pub use iced_native::*;
@@ -34,11 +36,27 @@ mod error;
 mod mode;
 mod proxy;
 
+// Events can only be recorded and replayed when the `record` feature is
+// explicitly enabled.
+#[cfg(feature = "record")]
+#[path = "recording/file.rs"]
+mod recording;
+#[cfg(not(feature = "record"))]
+#[path = "recording/null.rs"]
+mod recording;
+
+/// The environment variable holding the file where events are recorded.
+const RECORD_VARIABLE: &str = "ICED_RECORD";
+
+/// The environment variable holding the file of a recording to replay.
+const REPLAY_VARIABLE: &str = "ICED_REPLAY";
+
 pub use application::Application;
 pub use clipboard::Clipboard;
 pub use error::Error;
 pub use mode::Mode;
 pub use proxy::Proxy;
+pub use recording::Recording;
 pub use settings::Settings;
 
 pub use iced_graphics::Viewport;
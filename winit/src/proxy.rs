@@ -1,14 +1,20 @@
 use iced_native::futures::{
     channel::mpsc,
-    task::{Context, Poll},
+    task::{self, ArcWake, Context, Poll, Waker},
     Sink,
 };
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 
 /// An event loop proxy that implements `Sink`.
+///
+/// The event loop receives `Some` message for every message sent through
+/// the [`Proxy`], and `None` when it is woken up by a local future.
+///
+/// [`Proxy`]: struct.Proxy.html
 #[derive(Debug)]
 pub struct Proxy<Message: 'static> {
-    raw: winit::event_loop::EventLoopProxy<Message>,
+    raw: winit::event_loop::EventLoopProxy<Option<Message>>,
 }
 
 impl<Message: 'static> Clone for Proxy<Message> {
@@ -23,11 +29,34 @@ impl<Message: 'static> Proxy<Message> {
     /// Creates a new [`Proxy`] from an `EventLoopProxy`.
     ///
     /// [`Proxy`]: struct.Proxy.html
-    pub fn new(raw: winit::event_loop::EventLoopProxy<Message>) -> Self {
+    pub fn new(
+        raw: winit::event_loop::EventLoopProxy<Option<Message>>,
+    ) -> Self {
         Self { raw }
     }
 }
 
+impl<Message: Send + 'static> Proxy<Message> {
+    /// Creates a `Waker` that wakes up the event loop of the [`Proxy`].
+    ///
+    /// [`Proxy`]: struct.Proxy.html
+    pub fn waker(&self) -> Waker {
+        task::waker(Arc::new(Wake(Mutex::new(self.raw.clone()))))
+    }
+}
+
+struct Wake<Message: 'static>(
+    Mutex<winit::event_loop::EventLoopProxy<Option<Message>>>,
+);
+
+impl<Message: Send + 'static> ArcWake for Wake<Message> {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        if let Ok(raw) = arc_self.0.lock() {
+            let _ = raw.send_event(None);
+        }
+    }
+}
+
 impl<Message: 'static> Sink<Message> for Proxy<Message> {
     type Error = mpsc::SendError;
 
@@ -42,7 +71,7 @@ impl<Message: 'static> Sink<Message> for Proxy<Message> {
         self: Pin<&mut Self>,
         message: Message,
     ) -> Result<(), Self::Error> {
-        let _ = self.raw.send_event(message);
+        let _ = self.raw.send_event(Some(message));
 
         Ok(())
     }
@@ -0,0 +1,397 @@
+use crate::{Event, Size, Viewport, RECORD_VARIABLE, REPLAY_VARIABLE};
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use winit::dpi::{LogicalPosition, PhysicalPosition, PhysicalSize};
+use winit::event_loop::ControlFlow;
+use winit::window::Window;
+
+/// A recording of the events of an application.
+///
+/// A [`Recording`] is configured with environment variables:
+///
+/// - `ICED_RECORD` writes every event, with its timestamp, to the given file.
+/// - `ICED_REPLAY` feeds the events of the given file to the application
+///   at the same pace they were recorded, ignoring any user input until the
+///   replay finishes.
+///
+/// A recording also stores the logical size and the scale factor of the
+/// window, which are restored before replaying it.
+///
+/// [`Recording`]: struct.Recording.html
+#[derive(Debug)]
+pub struct Recording {
+    state: State,
+}
+
+#[derive(Debug)]
+enum State {
+    Idle,
+    Recording {
+        start: Instant,
+        file: io::BufWriter<fs::File>,
+    },
+    Replaying {
+        start: Instant,
+        events: VecDeque<(Duration, Event)>,
+        cursor_position: Option<LogicalPosition<f64>>,
+    },
+}
+
+/// The first line of a recording.
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    width: f32,
+    height: f32,
+    scale_factor: f64,
+}
+
+/// A line of a recording, after its [`Header`].
+///
+/// [`Header`]: struct.Header.html
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    time: f64,
+    event: Event,
+}
+
+impl Recording {
+    /// Starts recording or replaying events, as configured in the
+    /// environment.
+    pub fn from_environment(window: &Window, viewport: &Viewport) -> Self {
+        let state = if let Some(path) = std::env::var_os(REPLAY_VARIABLE) {
+            match replay(Path::new(&path), window, viewport) {
+                Ok(state) => state,
+                Err(error) => {
+                    log::warn!(
+                        "Failed to replay events from {}: {}",
+                        Path::new(&path).display(),
+                        error
+                    );
+
+                    State::Idle
+                }
+            }
+        } else if let Some(path) = std::env::var_os(RECORD_VARIABLE) {
+            match record(Path::new(&path), viewport) {
+                Ok(state) => state,
+                Err(error) => {
+                    log::warn!(
+                        "Failed to record events to {}: {}",
+                        Path::new(&path).display(),
+                        error
+                    );
+
+                    State::Idle
+                }
+            }
+        } else {
+            State::Idle
+        };
+
+        Recording { state }
+    }
+
+    /// Returns whether the [`Recording`] is being replayed.
+    ///
+    /// User input should be ignored while replaying.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn is_replaying(&self) -> bool {
+        matches!(self.state, State::Replaying { .. })
+    }
+
+    /// Records an [`Event`] produced by the user, if recording.
+    ///
+    /// Every event is written immediately, so the recording survives a
+    /// crash of the application.
+    ///
+    /// [`Event`]: enum.Event.html
+    pub fn record(&mut self, event: &Event) {
+        if let State::Recording { start, file } = &mut self.state {
+            let entry = Entry {
+                time: start.elapsed().as_secs_f64(),
+                event: event.clone(),
+            };
+
+            if let Err(error) = write_line(file, &entry) {
+                log::warn!("Failed to record event: {}", error);
+
+                self.state = State::Idle;
+            }
+        }
+    }
+
+    /// Returns the replayed events that are due, if replaying.
+    ///
+    /// The provided cursor position is replaced by the replayed one, and the
+    /// window is resized to match any replayed resize.
+    pub fn replay(
+        &mut self,
+        window: &Window,
+        viewport: &mut Viewport,
+        resized: &mut bool,
+        cursor_position: &mut PhysicalPosition<f64>,
+    ) -> Vec<Event> {
+        let size = viewport.physical_size();
+        let due = self.due_events(viewport, cursor_position);
+        let new_size = viewport.physical_size();
+
+        if new_size != size {
+            window.set_inner_size(PhysicalSize::new(
+                new_size.width,
+                new_size.height,
+            ));
+
+            *resized = true;
+        }
+
+        due
+    }
+
+    fn due_events(
+        &mut self,
+        viewport: &mut Viewport,
+        cursor_position: &mut PhysicalPosition<f64>,
+    ) -> Vec<Event> {
+        let (start, events, replayed_cursor) = match &mut self.state {
+            State::Replaying {
+                start,
+                events,
+                cursor_position,
+            } => (start, events, cursor_position),
+            _ => return Vec::new(),
+        };
+
+        let elapsed = start.elapsed();
+        let mut due = Vec::new();
+
+        while events.front().map(|(time, _)| *time <= elapsed) == Some(true) {
+            let (_, event) = events.pop_front().expect("Pop replayed event");
+
+            match event {
+                Event::Mouse(crate::mouse::Event::CursorMoved { x, y }) => {
+                    *replayed_cursor =
+                        Some(LogicalPosition::new(f64::from(x), f64::from(y)));
+                }
+                Event::Mouse(crate::mouse::Event::CursorLeft) => {
+                    *replayed_cursor = None;
+                }
+                Event::Window(crate::window::Event::Resized {
+                    width,
+                    height,
+                }) => {
+                    // The event is processed with the replayed logical size,
+                    // before the window reports its new size
+                    let scale_factor = viewport.scale_factor();
+
+                    *viewport = Viewport::with_physical_size(
+                        Size::new(
+                            (f64::from(width) * scale_factor).round() as u32,
+                            (f64::from(height) * scale_factor).round() as u32,
+                        ),
+                        scale_factor,
+                    );
+                }
+                _ => {}
+            }
+
+            due.push(event);
+        }
+
+        *cursor_position = match replayed_cursor {
+            Some(position) => position.to_physical(viewport.scale_factor()),
+            None => PhysicalPosition::new(-1.0, -1.0),
+        };
+
+        if events.is_empty() {
+            log::info!("Replay finished");
+
+            self.state = State::Idle;
+        }
+
+        due
+    }
+
+    /// Makes sure the event loop wakes up when the next replayed event is
+    /// due.
+    pub fn wake_up(&self, control_flow: &mut ControlFlow) {
+        let next = match &self.state {
+            State::Replaying { start, events, .. } => match events.front() {
+                Some((time, _)) => *start + *time,
+                None => return,
+            },
+            _ => return,
+        };
+
+        *control_flow = match *control_flow {
+            ControlFlow::Wait => ControlFlow::WaitUntil(next),
+            ControlFlow::WaitUntil(instant) => {
+                ControlFlow::WaitUntil(instant.min(next))
+            }
+            control_flow => control_flow,
+        };
+    }
+}
+
+fn record(path: &Path, viewport: &Viewport) -> io::Result<State> {
+    let mut file = io::BufWriter::new(fs::File::create(path)?);
+    let size = viewport.logical_size();
+
+    write_line(
+        &mut file,
+        &Header {
+            width: size.width,
+            height: size.height,
+            scale_factor: viewport.scale_factor(),
+        },
+    )?;
+
+    Ok(State::Recording {
+        start: Instant::now(),
+        file,
+    })
+}
+
+fn replay(
+    path: &Path,
+    window: &Window,
+    viewport: &Viewport,
+) -> io::Result<State> {
+    let (header, events) = load(io::BufReader::new(fs::File::open(path)?))?;
+
+    if (header.scale_factor - viewport.scale_factor()).abs() > f64::EPSILON {
+        log::warn!(
+            "The recording used a scale factor of {}, but the current one \
+             is {}. Rendering may differ.",
+            header.scale_factor,
+            viewport.scale_factor()
+        );
+    }
+
+    // Layout depends on the logical size of the window
+    window.set_inner_size(PhysicalSize::new(
+        (f64::from(header.width) * viewport.scale_factor()).round() as u32,
+        (f64::from(header.height) * viewport.scale_factor()).round() as u32,
+    ));
+
+    Ok(State::Replaying {
+        start: Instant::now(),
+        events,
+        cursor_position: None,
+    })
+}
+
+fn load(
+    reader: impl BufRead,
+) -> io::Result<(Header, VecDeque<(Duration, Event)>)> {
+    let mut lines = reader.lines();
+
+    let header: Header = match lines.next() {
+        Some(line) => serde_json::from_str(&line?)?,
+        None => {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "the recording is empty",
+            ))
+        }
+    };
+
+    let mut events = VecDeque::new();
+
+    for line in lines {
+        let entry: Entry = serde_json::from_str(&line?)?;
+
+        // `Duration::from_secs_f64` panics on negative or huge values
+        if !(entry.time.is_finite()
+            && entry.time >= 0.0
+            && entry.time < u64::MAX as f64)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid event time: {}", entry.time),
+            ));
+        }
+
+        events.push_back((Duration::from_secs_f64(entry.time), entry.event));
+    }
+
+    Ok((header, events))
+}
+
+fn write_line<T: Serialize>(
+    file: &mut io::BufWriter<fs::File>,
+    value: &T,
+) -> io::Result<()> {
+    serde_json::to_writer(&mut *file, value)?;
+    writeln!(file)?;
+
+    file.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mouse, window};
+
+    fn replaying(recording: &str) -> Recording {
+        let (_, events) = load(recording.as_bytes()).expect("Load recording");
+
+        Recording {
+            state: State::Replaying {
+                start: Instant::now(),
+                events,
+                cursor_position: None,
+            },
+        }
+    }
+
+    #[test]
+    fn replays_resizes() {
+        let mut recording = replaying(
+            r#"{"width":100.0,"height":100.0,"scale_factor":2.0}
+{"time":0.0,"event":{"Mouse":{"CursorMoved":{"x":10.0,"y":20.0}}}}
+{"time":0.0,"event":{"Window":{"Resized":{"width":300,"height":150}}}}
+{"time":3600.0,"event":{"Mouse":"CursorLeft"}}"#,
+        );
+
+        let mut viewport =
+            Viewport::with_physical_size(Size::new(200, 200), 2.0);
+        let mut cursor_position = PhysicalPosition::new(-1.0, -1.0);
+
+        let events = recording.due_events(&mut viewport, &mut cursor_position);
+
+        assert_eq!(
+            events,
+            vec![
+                Event::Mouse(mouse::Event::CursorMoved { x: 10.0, y: 20.0 }),
+                Event::Window(window::Event::Resized {
+                    width: 300,
+                    height: 150,
+                }),
+            ]
+        );
+        assert_eq!(viewport.physical_size(), Size::new(600, 300));
+        assert_eq!(viewport.logical_size(), Size::new(300.0, 150.0));
+        assert_eq!(cursor_position, PhysicalPosition::new(20.0, 40.0));
+        assert!(recording.is_replaying());
+    }
+
+    #[test]
+    fn rejects_invalid_times() {
+        for time in &["-1.0", "1e300"] {
+            let recording = format!(
+                "{}\n{{\"time\":{},\"event\":{{\"Mouse\":\"CursorLeft\"}}}}",
+                r#"{"width":100.0,"height":100.0,"scale_factor":1.0}"#, time
+            );
+
+            let error = load(recording.as_bytes()).unwrap_err();
+
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{Event, Viewport, RECORD_VARIABLE, REPLAY_VARIABLE};
+
+use winit::dpi::PhysicalPosition;
+use winit::event_loop::ControlFlow;
+use winit::window::Window;
+
+/// A recording of the events of an application.
+///
+/// Recording and replaying events needs the `record` feature.
+#[derive(Debug)]
+pub struct Recording;
+
+impl Recording {
+    /// Warns if events were requested to be recorded or replayed.
+    pub fn from_environment(_window: &Window, _viewport: &Viewport) -> Self {
+        if std::env::var_os(RECORD_VARIABLE).is_some()
+            || std::env::var_os(REPLAY_VARIABLE).is_some()
+        {
+            log::warn!(
+                "Recording and replaying events needs the `record` feature"
+            );
+        }
+
+        Recording
+    }
+
+    /// Returns whether the recording is being replayed.
+    pub fn is_replaying(&self) -> bool {
+        false
+    }
+
+    /// Records an [`Event`] produced by the user.
+    ///
+    /// [`Event`]: enum.Event.html
+    pub fn record(&mut self, _event: &Event) {}
+
+    /// Returns the replayed events that are due.
+    pub fn replay(
+        &mut self,
+        _window: &Window,
+        _viewport: &mut Viewport,
+        _resized: &mut bool,
+        _cursor_position: &mut PhysicalPosition<f64>,
+    ) -> Vec<Event> {
+        Vec::new()
+    }
+
+    /// Makes sure the event loop wakes up when the next replayed event is
+    /// due.
+    pub fn wake_up(&self, _control_flow: &mut ControlFlow) {}
+}